@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
 use solana_program::native_token::LAMPORTS_PER_SOL;
 
 #[constant]
@@ -7,6 +8,34 @@ pub const MIN_SOL_FEE: u64 = (0.001 * LAMPORTS_PER_SOL as f64) as u64;
 #[constant]
 pub const MAX_BASIS_POINTS: u64 = 10000;
 
+/// How far in the past a vault start date may be, to allow for clock drift between clients and
+/// the cluster.
+#[constant]
+pub const START_DATE_GRACE_PERIOD: u64 = 300;
+
+#[constant]
+pub const MAX_TRANCHES: u64 = 32;
+
+#[constant]
+pub const MAX_BENEFICIARIES: u64 = 16;
+
+#[constant]
+pub const MAX_FEE_DISCOUNT_TIERS: u64 = 4;
+
+#[constant]
+pub const DEFAULT_DENIED_MINT_EXTENSIONS: u64 = 1 << ExtensionType::NonTransferable as u64
+    | 1 << ExtensionType::PermanentDelegate as u64
+    | 1 << ExtensionType::TransferHook as u64;
+
+#[constant]
+pub const PAUSE_CREATE: u8 = 1 << 0;
+
+#[constant]
+pub const PAUSE_DEPOSIT: u8 = 1 << 1;
+
+#[constant]
+pub const PAUSE_DISBURSE: u8 = 1 << 2;
+
 #[constant]
 pub const CONFIG_SEED: &[u8] = b"config";
 
@@ -16,5 +45,36 @@ pub const VAULT_SEED: &[u8] = b"vault";
 #[constant]
 pub const VAULT_ATA_SEED: &[u8] = b"vault_ata";
 
+#[constant]
+pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
+
+#[constant]
+pub const FEE_STATS_SEED: &[u8] = b"fee_stats";
+
+/// Native vaults keep their own fee stats so their lamport fees are not mixed with the token fees
+/// of wrapped SOL vaults.
+#[constant]
+pub const NATIVE_FEE_STATS_SEED: &[u8] = b"native_fee_stats";
+
+#[constant]
+pub const MINT_FEE_OVERRIDE_SEED: &[u8] = b"mint_fee_override";
+
+#[constant]
+pub const PENDING_CONFIG_CHANGE_SEED: &[u8] = b"pending_config_change";
+
+#[constant]
+pub const AUTOPAY_ESCROW_SEED: &[u8] = b"autopay_escrow";
+
+/// The lamports charged per transaction to crank an autopay vault, on top of the rent for the
+/// recipient token account.
+#[constant]
+pub const AUTOPAY_FEE_PER_TX: u64 = 1000;
+
+#[constant]
+pub const SCHEDULE_SEED: &[u8] = b"schedule";
+
+#[constant]
+pub const BENEFICIARIES_SEED: &[u8] = b"beneficiaries";
+
 #[constant]
 pub const GOVERNANCE_TOKEN_MINT_SEED: &[u8] = b"governance_token_mint";
@@ -33,4 +33,72 @@ pub enum ValhallaError {
     /// 0x1777 - 6007
     #[msg("Fee payment failed!")]
     FeePaymentFailed,
+
+    /// 0x1778 - 6008
+    #[msg("Cliff is invalid!")]
+    InvalidCliff,
+
+    /// 0x1779 - 6009
+    #[msg("Schedule is invalid!")]
+    InvalidSchedule,
+
+    /// 0x177a - 6010
+    #[msg("Batch is invalid!")]
+    InvalidBatch,
+
+    /// 0x177b - 6011
+    #[msg("Deposit is invalid!")]
+    InvalidDeposit,
+
+    /// 0x177c - 6012
+    #[msg("Beneficiaries are invalid!")]
+    InvalidBeneficiaries,
+
+    /// 0x177d - 6013
+    #[msg("Mint extensions are invalid!")]
+    InvalidMintExtensions,
+
+    /// 0x177e - 6014
+    #[msg("Vault type is invalid!")]
+    InvalidVaultType,
+
+    /// 0x177f - 6015
+    #[msg("Math overflow!")]
+    MathOverflow,
+
+    /// 0x1780 - 6016
+    #[msg("Payout interval is invalid!")]
+    InvalidPayoutInterval,
+
+    /// 0x1781 - 6017
+    #[msg("Start date is invalid!")]
+    InvalidStartDate,
+
+    /// 0x1782 - 6018
+    #[msg("Amount is invalid!")]
+    InvalidAmount,
+
+    /// 0x1783 - 6019
+    #[msg("Config change is locked!")]
+    ConfigChangeLocked,
+
+    /// 0x1784 - 6020
+    #[msg("The program is paused!")]
+    Paused,
+
+    /// 0x1785 - 6021
+    #[msg("Fee discount tiers are invalid!")]
+    InvalidFeeDiscountTiers,
+
+    /// 0x1786 - 6022
+    #[msg("Autopay escrow is invalid!")]
+    InvalidAutopayEscrow,
+
+    /// 0x1787 - 6023
+    #[msg("The account has already been migrated!")]
+    AlreadyMigrated,
+
+    /// 0x1788 - 6024
+    #[msg("Transfer authority is invalid!")]
+    InvalidTransferAuthority,
 }
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+
+use crate::{state::Config, ConfigChange, FeeDiscountTier, MintExtensionPolicy};
+
+#[event]
+pub struct VaultCreated {
+    pub vault: Pubkey,
+    pub creator: Pubkey,
+    pub recipient: Pubkey,
+    pub mint: Pubkey,
+    pub deposit_amount: u64,
+    pub token_fee_amount: u64,
+    pub sol_fee_amount: u64,
+    pub autopay_amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct VaultDisbursed {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub signer: Pubkey,
+    pub amount: u64,
+    pub total_withdrawn: u64,
+    pub number_of_payments_made: u64,
+    pub governance_token_amount: u64,
+    pub autopay_fee_amount: u64,
+    pub crank_reward_governance_token_amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct VaultDeposited {
+    pub vault: Pubkey,
+    pub depositor: Pubkey,
+    pub deposit_amount: u64,
+    pub token_fee_amount: u64,
+    pub autopay_amount: u64,
+    pub total_number_of_payouts: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct VaultCancelled {
+    pub vault: Pubkey,
+    pub signer: Pubkey,
+    pub recipient_amount: u64,
+    pub creator_amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct VaultClosed {
+    pub vault: Pubkey,
+    pub creator: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct VaultMigrated {
+    pub vault: Pubkey,
+    pub total_withdrawn: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct VaultSplit {
+    pub vault: Pubkey,
+    pub number_of_beneficiaries: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct VaultRecipientTransferred {
+    pub vault: Pubkey,
+    pub previous_recipient: Pubkey,
+    pub new_recipient: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub dev_treasury: Pubkey,
+    pub dao_treasury: Pubkey,
+    pub dev_fee: u64,
+    pub autopay_multiplier: u64,
+    pub token_fee_basis_points: u64,
+    pub governance_token_amount: u64,
+    pub config_change_delay: u64,
+    pub mint_extension_policy: MintExtensionPolicy,
+    pub mint_extensions: u64,
+    pub allow_freeze_authority: bool,
+    pub paused: bool,
+    pub pause_flags: u8,
+    pub fee_discount_tiers: Vec<FeeDiscountTier>,
+    pub crank_reward_lamports: u64,
+    pub crank_reward_governance_token_amount: u64,
+    pub timestamp: u64,
+}
+
+impl ConfigUpdated {
+    pub fn new(config: &Config) -> Result<Self> {
+        Ok(Self {
+            admin: config.admin,
+            pending_admin: config.pending_admin,
+            dev_treasury: config.dev_treasury,
+            dao_treasury: config.dao_treasury,
+            dev_fee: config.dev_fee,
+            autopay_multiplier: config.autopay_multiplier,
+            token_fee_basis_points: config.token_fee_basis_points,
+            governance_token_amount: config.governance_token_amount,
+            config_change_delay: config.config_change_delay,
+            mint_extension_policy: config.mint_extension_policy.clone(),
+            mint_extensions: config.mint_extensions,
+            allow_freeze_authority: config.allow_freeze_authority,
+            paused: config.paused,
+            pause_flags: config.pause_flags,
+            fee_discount_tiers: config.fee_discount_tiers.clone(),
+            crank_reward_lamports: config.crank_reward_lamports,
+            crank_reward_governance_token_amount: config.crank_reward_governance_token_amount,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        })
+    }
+}
+
+#[event]
+pub struct ConfigChangeQueued {
+    pub config_change: ConfigChange,
+    pub eta: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub config_change: ConfigChange,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct FeesWithdrawn {
+    pub mint: Pubkey,
+    pub dev_treasury: Pubkey,
+    pub dao_treasury: Pubkey,
+    pub token_fee_amount: u64,
+    pub sol_fee_amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct MintFeeOverrideUpdated {
+    pub mint: Pubkey,
+    pub token_fee_basis_points: u64,
+    pub dev_fee: u64,
+    pub fee_waived: bool,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct MintFeeOverrideClosed {
+    pub mint: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct GovernanceTokensMinted {
+    pub receiver: Pubkey,
+    pub amount: u64,
+    pub timestamp: u64,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, errors::ValhallaError, events::ConfigUpdated, state::Config};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut)]
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::CONFIG_SEED],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept(&mut self) -> Result<()> {
+        // Only the proposed admin can take over the configuration.
+        require!(
+            self.config.pending_admin != Pubkey::default()
+                && self.config.pending_admin == self.new_admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        self.config.admin = self.new_admin.key();
+        self.config.pending_admin = Pubkey::default();
+
+        emit!(ConfigUpdated::new(&self.config)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::ConfigChangeCancelled,
+    state::{Config, PendingConfigChange},
+};
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        has_one = admin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [constants::PENDING_CONFIG_CHANGE_SEED],
+        bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+}
+
+impl<'info> CancelConfigChange<'info> {
+    pub fn cancel(&mut self) -> Result<()> {
+        // Ensure that the caller is authorized to update the configuration.
+        require!(
+            self.config.admin == self.admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        emit!(ConfigChangeCancelled {
+            config_change: self.pending_config_change.config_change.clone(),
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+}
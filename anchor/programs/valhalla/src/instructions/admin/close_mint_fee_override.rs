@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::MintFeeOverrideClosed,
+    state::{Config, MintFeeOverride},
+};
+
+#[derive(Accounts)]
+pub struct CloseMintFeeOverride<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        has_one = admin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [mint.key().as_ref(), constants::MINT_FEE_OVERRIDE_SEED],
+        bump
+    )]
+    pub mint_fee_override: Box<Account<'info, MintFeeOverride>>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+impl<'info> CloseMintFeeOverride<'info> {
+    pub fn close(&mut self) -> Result<()> {
+        // Ensure that the caller is authorized to update the configuration.
+        require!(
+            self.config.admin == self.admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        emit!(MintFeeOverrideClosed {
+            mint: self.mint.key(),
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+}
@@ -8,7 +8,9 @@ use anchor_spl::{
     token_interface::{Mint, TokenInterface},
 };
 
-use crate::{constants, errors::ValhallaError, state::Config};
+use crate::{
+    constants, errors::ValhallaError, events::ConfigUpdated, state::Config, MintExtensionPolicy,
+};
 
 #[derive(Accounts)]
 #[instruction(
@@ -19,7 +21,8 @@ use crate::{constants, errors::ValhallaError, state::Config};
     dev_fee: u64,
     token_fee_basis_points: u64,
     create_governance_token_amount: u64,
-    governance_token_amount: u64
+    governance_token_amount: u64,
+    config_change_delay: u64
 )]
 pub struct CreateConfig<'info> {
     #[account(mut)]
@@ -76,6 +79,7 @@ impl<'info> CreateConfig<'info> {
         autopay_multiplier: u64,
         token_fee_basis_points: u64,
         governance_token_amount: u64,
+        config_change_delay: u64,
         bumps: &CreateConfigBumps,
     ) -> Result<()> {
         // If the config account is already initialized, return an error.
@@ -92,6 +96,7 @@ impl<'info> CreateConfig<'info> {
 
         self.config.set_inner(Config {
             admin: self.admin.to_account_info().key(),
+            pending_admin: Pubkey::default(),
             dev_treasury: self.dev_treasury.to_account_info().key(),
             dao_treasury: self.dao_treasury.to_account_info().key(),
             governance_token_mint_key: self.governance_token_mint.to_account_info().key(),
@@ -99,6 +104,15 @@ impl<'info> CreateConfig<'info> {
             autopay_multiplier,
             token_fee_basis_points,
             governance_token_amount,
+            config_change_delay,
+            mint_extension_policy: MintExtensionPolicy::Denylist,
+            mint_extensions: constants::DEFAULT_DENIED_MINT_EXTENSIONS,
+            allow_freeze_authority: true,
+            paused: false,
+            pause_flags: 0,
+            fee_discount_tiers: vec![],
+            crank_reward_lamports: 0,
+            crank_reward_governance_token_amount: 0,
         });
 
         let seeds = &[
@@ -124,6 +138,8 @@ impl<'info> CreateConfig<'info> {
             .token_standard(TokenStandard::Fungible)
             .invoke_signed(signer_seeds)?;
 
+        emit!(ConfigUpdated::new(&self.config)?);
+
         // TODO: Mint initial allocations of governance tokens to the DAO treasury and development team treasury.
 
         Ok(())
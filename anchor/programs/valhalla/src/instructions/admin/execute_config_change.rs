@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::ConfigUpdated,
+    state::{Config, PendingConfigChange},
+    ConfigChange,
+};
+
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        has_one = admin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [constants::PENDING_CONFIG_CHANGE_SEED],
+        bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+}
+
+impl<'info> ExecuteConfigChange<'info> {
+    pub fn execute(&mut self) -> Result<()> {
+        // Ensure that the caller is authorized to update the configuration.
+        require!(
+            self.config.admin == self.admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(
+            self.pending_config_change.eta <= now,
+            ValhallaError::ConfigChangeLocked
+        );
+
+        match self.pending_config_change.config_change {
+            ConfigChange::DevFee(dev_fee) => self.config.dev_fee = dev_fee,
+            ConfigChange::TokenFeeBasisPoints(token_fee_basis_points) => {
+                self.config.token_fee_basis_points = token_fee_basis_points
+            }
+            ConfigChange::GovernanceTokenAmount(governance_token_amount) => {
+                self.config.governance_token_amount = governance_token_amount
+            }
+            ConfigChange::DaoTreasury(dao_treasury) => self.config.dao_treasury = dao_treasury,
+            ConfigChange::ConfigChangeDelay(config_change_delay) => {
+                self.config.config_change_delay = config_change_delay
+            }
+            ConfigChange::CrankRewardLamports(crank_reward_lamports) => {
+                self.config.crank_reward_lamports = crank_reward_lamports
+            }
+            ConfigChange::CrankRewardGovernanceTokenAmount(
+                crank_reward_governance_token_amount,
+            ) => {
+                self.config.crank_reward_governance_token_amount =
+                    crank_reward_governance_token_amount
+            }
+        }
+
+        emit!(ConfigUpdated::new(&self.config)?);
+
+        Ok(())
+    }
+}
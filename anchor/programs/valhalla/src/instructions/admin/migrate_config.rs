@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use solana_program::system_instruction;
+
+use crate::{constants, errors::ValhallaError, events::ConfigUpdated, state::Config};
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: a config with the legacy layout cannot be loaded as a `Config`, so it is checked and
+    /// deserialized in the instruction.
+    #[account(
+        mut,
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateConfig<'info> {
+    pub fn migrate(&mut self, config_change_delay: u64) -> Result<()> {
+        let config = self.config.to_account_info();
+        require!(
+            config.data_len() == Config::LEGACY_SPACE,
+            ValhallaError::AlreadyMigrated
+        );
+
+        // The admin is the first field after the discriminator in every layout.
+        {
+            let data = config.try_borrow_data()?;
+            require!(
+                data[8..40] == self.admin.key().to_bytes(),
+                ValhallaError::Unauthorized
+            );
+        }
+
+        let rent = Rent::get()?.minimum_balance(Config::INIT_SPACE);
+        let shortfall = rent.saturating_sub(config.lamports());
+        if shortfall > 0 {
+            let from = self.admin.to_account_info();
+            let transfer_ix = system_instruction::transfer(from.key, config.key, shortfall);
+
+            solana_program::program::invoke(
+                &transfer_ix,
+                &[from, config.clone(), self.system_program.to_account_info()],
+            )?;
+        }
+
+        // The new fields are appended to the legacy layout, so the zeroed tail deserializes as a
+        // config without a pending admin, pause or fee discounts. The rest get the defaults of a
+        // new config.
+        config.realloc(Config::INIT_SPACE, true)?;
+        let mut migrated_config = Config::try_deserialize(&mut &config.try_borrow_data()?[..])?;
+        migrated_config.config_change_delay = config_change_delay;
+        migrated_config.mint_extensions = constants::DEFAULT_DENIED_MINT_EXTENSIONS;
+        migrated_config.allow_freeze_authority = true;
+        migrated_config.try_serialize(&mut &mut config.try_borrow_mut_data()?[..])?;
+
+        emit!(ConfigUpdated::new(&migrated_config)?);
+
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use anchor_spl::{
     token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
 };
 
-use crate::{constants, Config};
+use crate::{constants, events::GovernanceTokensMinted, Config};
 
 #[derive(Accounts)]
 pub struct MintGovernanceTokens<'info> {
@@ -66,6 +66,14 @@ impl<'info> MintGovernanceTokens<'info> {
 
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
 
-        mint_to(cpi_ctx, amount)
+        mint_to(cpi_ctx, amount)?;
+
+        emit!(GovernanceTokensMinted {
+            receiver: self.receiver.key(),
+            amount,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        });
+
+        Ok(())
     }
 }
@@ -1,15 +1,31 @@
+mod accept_admin;
+mod cancel_config_change;
+mod close_mint_fee_override;
 mod create_config;
+mod execute_config_change;
+mod migrate_config;
 mod mint_governance_tokens;
-mod update_admin;
-mod update_dao_treasury;
-mod update_dev_fee;
-mod update_governance_token_amount;
-mod update_token_fee_basis_points;
+mod propose_admin;
+mod queue_config_change;
+mod set_mint_fee_override;
+mod update_fee_discount_tiers;
+mod update_mint_extension_policy;
+mod update_pause;
+mod withdraw_fees;
+mod withdraw_sol_fees;
 
+pub use accept_admin::*;
+pub use cancel_config_change::*;
+pub use close_mint_fee_override::*;
 pub use create_config::*;
+pub use execute_config_change::*;
+pub use migrate_config::*;
 pub use mint_governance_tokens::*;
-pub use update_admin::*;
-pub use update_dao_treasury::*;
-pub use update_dev_fee::*;
-pub use update_governance_token_amount::*;
-pub use update_token_fee_basis_points::*;
+pub use propose_admin::*;
+pub use queue_config_change::*;
+pub use set_mint_fee_override::*;
+pub use update_fee_discount_tiers::*;
+pub use update_mint_extension_policy::*;
+pub use update_pause::*;
+pub use withdraw_fees::*;
+pub use withdraw_sol_fees::*;
@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants, errors::ValhallaError, state::Config};
+use crate::{constants, errors::ValhallaError, events::ConfigUpdated, state::Config};
 
 #[derive(Accounts)]
-pub struct UpdateAdmin<'info> {
+pub struct ProposeAdmin<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
@@ -18,15 +18,18 @@ pub struct UpdateAdmin<'info> {
     pub config: Box<Account<'info, Config>>,
 }
 
-impl<'info> UpdateAdmin<'info> {
-    pub fn update(&mut self) -> Result<()> {
+impl<'info> ProposeAdmin<'info> {
+    pub fn propose(&mut self) -> Result<()> {
         // Ensure that the caller is authorized to update the configuration.
         require!(
             self.config.admin == self.admin.key(),
             ValhallaError::Unauthorized
         );
 
-        self.config.admin = self.new_admin.key();
+        // The admin only changes once the new admin accepts.
+        self.config.pending_admin = self.new_admin.key();
+
+        emit!(ConfigUpdated::new(&self.config)?);
 
         Ok(())
     }
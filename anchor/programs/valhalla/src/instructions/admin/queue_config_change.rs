@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::ConfigChangeQueued,
+    state::{Config, PendingConfigChange},
+    ConfigChange,
+};
+
+#[derive(Accounts)]
+pub struct QueueConfigChange<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        has_one = admin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [constants::PENDING_CONFIG_CHANGE_SEED],
+        space = PendingConfigChange::INIT_SPACE,
+        bump
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChange>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> QueueConfigChange<'info> {
+    pub fn queue(&mut self, config_change: ConfigChange) -> Result<()> {
+        // Ensure that the caller is authorized to update the configuration.
+        require!(
+            self.config.admin == self.admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        match config_change {
+            ConfigChange::DevFee(dev_fee) => require!(
+                dev_fee >= constants::MIN_SOL_FEE,
+                ValhallaError::InvalidSolFee
+            ),
+            ConfigChange::TokenFeeBasisPoints(token_fee_basis_points) => require!(
+                token_fee_basis_points <= constants::MAX_BASIS_POINTS,
+                ValhallaError::InvalidTokenFeeBasisPoints
+            ),
+            _ => {}
+        }
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        let eta = now
+            .checked_add(self.config.config_change_delay)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        self.pending_config_change.set_inner(PendingConfigChange {
+            config_change: config_change.clone(),
+            eta,
+        });
+
+        emit!(ConfigChangeQueued {
+            config_change,
+            eta,
+            timestamp: now,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::MintFeeOverrideUpdated,
+    state::{Config, MintFeeOverride},
+};
+
+#[derive(Accounts)]
+pub struct SetMintFeeOverride<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        has_one = admin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [mint.key().as_ref(), constants::MINT_FEE_OVERRIDE_SEED],
+        space = MintFeeOverride::INIT_SPACE,
+        bump
+    )]
+    pub mint_fee_override: Box<Account<'info, MintFeeOverride>>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetMintFeeOverride<'info> {
+    pub fn set(
+        &mut self,
+        token_fee_basis_points: u64,
+        dev_fee: u64,
+        fee_waived: bool,
+    ) -> Result<()> {
+        // Ensure that the caller is authorized to update the configuration.
+        require!(
+            self.config.admin == self.admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        // Fee increases go through the config change delay, so an override can only lower the
+        // fees in the config.
+        require!(
+            token_fee_basis_points <= self.config.token_fee_basis_points,
+            ValhallaError::InvalidTokenFeeBasisPoints
+        );
+        require!(dev_fee <= self.config.dev_fee, ValhallaError::InvalidSolFee);
+        require!(
+            fee_waived || dev_fee >= constants::MIN_SOL_FEE,
+            ValhallaError::InvalidSolFee
+        );
+
+        self.mint_fee_override.set_inner(MintFeeOverride {
+            mint: self.mint.key(),
+            token_fee_basis_points,
+            dev_fee,
+            fee_waived,
+        });
+
+        emit!(MintFeeOverrideUpdated {
+            mint: self.mint.key(),
+            token_fee_basis_points,
+            dev_fee,
+            fee_waived,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants, errors::ValhallaError, events::ConfigUpdated, state::Config, FeeDiscountTier,
+};
+
+#[derive(Accounts)]
+pub struct UpdateFeeDiscountTiers<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        has_one = admin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+impl<'info> UpdateFeeDiscountTiers<'info> {
+    pub fn update(&mut self, fee_discount_tiers: Vec<FeeDiscountTier>) -> Result<()> {
+        // Ensure that the caller is authorized to update the configuration.
+        require!(
+            self.config.admin == self.admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        // Tiers must be ordered by the governance token amount they require.
+        require!(
+            fee_discount_tiers.len() as u64 <= constants::MAX_FEE_DISCOUNT_TIERS,
+            ValhallaError::InvalidFeeDiscountTiers
+        );
+        require!(
+            fee_discount_tiers
+                .iter()
+                .all(|tier| tier.discount_basis_points <= constants::MAX_BASIS_POINTS),
+            ValhallaError::InvalidFeeDiscountTiers
+        );
+        require!(
+            fee_discount_tiers
+                .windows(2)
+                .all(|pair| pair[0].min_governance_token_amount
+                    < pair[1].min_governance_token_amount),
+            ValhallaError::InvalidFeeDiscountTiers
+        );
+
+        self.config.fee_discount_tiers = fee_discount_tiers;
+
+        emit!(ConfigUpdated::new(&self.config)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants, errors::ValhallaError, events::ConfigUpdated, state::Config, MintExtensionPolicy,
+};
+
+#[derive(Accounts)]
+pub struct UpdateMintExtensionPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        has_one = admin,
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+impl<'info> UpdateMintExtensionPolicy<'info> {
+    pub fn update(
+        &mut self,
+        mint_extension_policy: MintExtensionPolicy,
+        mint_extensions: u64,
+        allow_freeze_authority: bool,
+    ) -> Result<()> {
+        // Ensure that the caller is authorized to update the configuration.
+        require!(
+            self.config.admin == self.admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        self.config.mint_extension_policy = mint_extension_policy;
+        self.config.mint_extensions = mint_extensions;
+        self.config.allow_freeze_authority = allow_freeze_authority;
+
+        emit!(ConfigUpdated::new(&self.config)?);
+
+        Ok(())
+    }
+}
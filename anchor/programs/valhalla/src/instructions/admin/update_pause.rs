@@ -1,14 +1,12 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants, errors::ValhallaError, state::Config};
+use crate::{constants, errors::ValhallaError, events::ConfigUpdated, state::Config};
 
 #[derive(Accounts)]
-pub struct UpdateDaoTreasury<'info> {
+pub struct UpdatePause<'info> {
     #[account(mut)]
     pub admin: Signer<'info>,
 
-    pub new_dao_treasury: SystemAccount<'info>,
-
     #[account(
         mut,
         seeds = [constants::CONFIG_SEED],
@@ -18,15 +16,18 @@ pub struct UpdateDaoTreasury<'info> {
     pub config: Box<Account<'info, Config>>,
 }
 
-impl<'info> UpdateDaoTreasury<'info> {
-    pub fn update(&mut self) -> Result<()> {
+impl<'info> UpdatePause<'info> {
+    pub fn update(&mut self, paused: bool, pause_flags: u8) -> Result<()> {
         // Ensure that the caller is authorized to update the configuration.
         require!(
             self.config.admin == self.admin.key(),
             ValhallaError::Unauthorized
         );
 
-        self.config.dao_treasury = self.new_dao_treasury.key();
+        self.config.paused = paused;
+        self.config.pause_flags = pause_flags;
+
+        emit!(ConfigUpdated::new(&self.config)?);
 
         Ok(())
     }
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::FeesWithdrawn,
+    state::{Config, FeeStats},
+    utils,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub dev_treasury: SystemAccount<'info>,
+
+    pub dao_treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        has_one = admin,
+        has_one = dev_treasury,
+        has_one = dao_treasury,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [mint.key().as_ref(), constants::FEE_STATS_SEED],
+        bump,
+    )]
+    pub fee_stats: Box<Account<'info, FeeStats>>,
+
+    #[account(
+        mut,
+        seeds = [mint.key().as_ref(), constants::FEE_VAULT_SEED],
+        bump,
+        token::mint = mint,
+        token::authority = fee_vault,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = dao_treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub dao_treasury_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> WithdrawFees<'info> {
+    pub fn withdraw(&mut self, bumps: &WithdrawFeesBumps) -> Result<()> {
+        // Ensure that the caller is authorized to withdraw the fees.
+        require!(
+            self.config.admin == self.admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        let token_fee_amount = self.fee_stats.get_pending_token_fees()?;
+        let sol_fee_amount = self.fee_stats.get_pending_sol_fees()?;
+
+        // Token fees are paid to the dao treasury
+        if token_fee_amount > 0 {
+            self.transfer(token_fee_amount, bumps)?;
+        }
+
+        // SOL fees are paid to the dev treasury
+        if sol_fee_amount > 0 {
+            self.fee_stats.sub_lamports(sol_fee_amount)?;
+            self.dev_treasury.add_lamports(sol_fee_amount)?;
+        }
+
+        self.fee_stats.withdrawn_token_fees = self.fee_stats.total_token_fees;
+        self.fee_stats.withdrawn_sol_fees = self.fee_stats.total_sol_fees;
+
+        emit!(FeesWithdrawn {
+            mint: self.mint.key(),
+            dev_treasury: self.dev_treasury.key(),
+            dao_treasury: self.dao_treasury.key(),
+            token_fee_amount,
+            sol_fee_amount,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+
+    fn transfer(&self, amount: u64, bumps: &WithdrawFeesBumps) -> Result<()> {
+        let mint_key = self.mint.key();
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            mint_key.as_ref(),
+            constants::FEE_VAULT_SEED,
+            &[bumps.fee_vault],
+        ]];
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.fee_vault.to_account_info(),
+            to: self.dao_treasury_ata.to_account_info(),
+            authority: self.fee_vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        utils::transfer_checked_with_fee(cpi_ctx, amount, self.mint.decimals)?;
+
+        Ok(())
+    }
+}
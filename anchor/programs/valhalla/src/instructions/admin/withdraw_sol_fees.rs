@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::FeesWithdrawn,
+    state::{Config, FeeStats},
+};
+
+#[derive(Accounts)]
+pub struct WithdrawSolFees<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(mut)]
+    pub dev_treasury: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub dao_treasury: SystemAccount<'info>,
+
+    #[account(
+        seeds = [constants::CONFIG_SEED],
+        bump,
+        has_one = admin,
+        has_one = dev_treasury,
+        has_one = dao_treasury,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [constants::NATIVE_FEE_STATS_SEED],
+        bump,
+    )]
+    pub fee_stats: Box<Account<'info, FeeStats>>,
+}
+
+impl<'info> WithdrawSolFees<'info> {
+    pub fn withdraw(&mut self) -> Result<()> {
+        // Ensure that the caller is authorized to withdraw the fees.
+        require!(
+            self.config.admin == self.admin.key(),
+            ValhallaError::Unauthorized
+        );
+
+        // Native vaults pay both fees in lamports, so the token fees are held alongside the SOL fees.
+        let token_fee_amount = self.fee_stats.get_pending_token_fees()?;
+        let sol_fee_amount = self.fee_stats.get_pending_sol_fees()?;
+
+        self.fee_stats.sub_lamports(
+            token_fee_amount
+                .checked_add(sol_fee_amount)
+                .ok_or(ValhallaError::MathOverflow)?,
+        )?;
+        self.dao_treasury.add_lamports(token_fee_amount)?;
+        self.dev_treasury.add_lamports(sol_fee_amount)?;
+
+        self.fee_stats.withdrawn_token_fees = self.fee_stats.total_token_fees;
+        self.fee_stats.withdrawn_sol_fees = self.fee_stats.total_sol_fees;
+
+        emit!(FeesWithdrawn {
+            mint: native_mint::ID,
+            dev_treasury: self.dev_treasury.key(),
+            dao_treasury: self.dao_treasury.key(),
+            token_fee_amount,
+            sol_fee_amount,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+}
@@ -2,12 +2,17 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{
-        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
-        TransferChecked,
+        close_account, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
     },
 };
 
-use crate::{constants, errors::ValhallaError, state::Vault, Authority};
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::VaultCancelled,
+    state::{AutopayEscrow, Beneficiaries, Schedule, Vault},
+    utils, Authority, VestingMode,
+};
 
 #[derive(Accounts)]
 pub struct CancelVault<'info> {
@@ -45,6 +50,36 @@ pub struct CancelVault<'info> {
     )]
     pub vault_ata: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            vault.key().as_ref(),
+            constants::SCHEDULE_SEED
+        ],
+        bump,
+    )]
+    pub schedule: Option<Account<'info, Schedule>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            vault.key().as_ref(),
+            constants::BENEFICIARIES_SEED
+        ],
+        bump,
+    )]
+    pub beneficiaries: Option<Box<Account<'info, Beneficiaries>>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        bump,
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
+
     #[account(
         init_if_needed,
         payer = signer,
@@ -53,6 +88,16 @@ pub struct CancelVault<'info> {
     )]
     pub creator_ata: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program,
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
     pub token_program: Interface<'info, TokenInterface>,
@@ -63,16 +108,63 @@ pub struct CancelVault<'info> {
 }
 
 impl<'info> CancelVault<'info> {
-    pub fn cancel(&mut self) -> Result<()> {
+    pub fn cancel(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
         self.validate_cancel_authority()?;
 
-        match self.vault_ata.amount {
-            0 => self.close_vault_ata(),
-            _ => {
-                self.transfer()?;
-                self.close_vault_ata()
+        // The unused autopay budget is refunded to the creator along with the vault.
+        require!(
+            !self.vault.autopay || self.autopay_escrow.is_some(),
+            ValhallaError::InvalidAutopayEscrow
+        );
+
+        // The schedule and beneficiaries are closed with the vault, so their rent is refunded too.
+        require!(
+            self.vault.vesting_mode != VestingMode::Scheduled || self.schedule.is_some(),
+            ValhallaError::InvalidSchedule
+        );
+        require!(
+            !self.vault.is_split || self.beneficiaries.is_some(),
+            ValhallaError::InvalidBeneficiaries
+        );
+
+        // The recipient keeps everything that has vested so far; only the rest is returned.
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let recipient_amount = match self.vault.is_locked(current_time)? {
+            true => 0,
+            false => self.vault.get_claimable_amount(
+                current_time,
+                self.vault_ata.amount,
+                self.schedule.as_deref(),
+            )?,
+        };
+        let creator_amount = self
+            .vault_ata
+            .amount
+            .checked_sub(recipient_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        if recipient_amount > 0 {
+            match self.vault.is_split {
+                true => self.transfer_to_beneficiaries(recipient_amount, remaining_accounts)?,
+                false => self.transfer(recipient_amount, self.recipient_ata.to_account_info())?,
             }
         }
+
+        if creator_amount > 0 {
+            self.transfer(creator_amount, self.creator_ata.to_account_info())?;
+        }
+
+        self.close_vault_ata()?;
+
+        emit!(VaultCancelled {
+            vault: self.vault.key(),
+            signer: self.signer.key(),
+            recipient_amount,
+            creator_amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
     }
 
     fn validate_cancel_authority(&self) -> Result<()> {
@@ -102,7 +194,55 @@ impl<'info> CancelVault<'info> {
         Ok(())
     }
 
-    fn transfer(&mut self) -> Result<()> {
+    fn transfer_to_beneficiaries(
+        &self,
+        amount: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let beneficiaries = match &self.beneficiaries {
+            Some(beneficiaries) => beneficiaries,
+            None => return Err(ValhallaError::InvalidBeneficiaries.into()),
+        };
+        require!(
+            remaining_accounts.len() == beneficiaries.beneficiaries.len(),
+            ValhallaError::InvalidBeneficiaries
+        );
+
+        let shares = beneficiaries.get_shares(amount)?;
+        for ((beneficiary, beneficiary_ata), share) in beneficiaries
+            .beneficiaries
+            .iter()
+            .zip(remaining_accounts)
+            .zip(shares)
+        {
+            // Each beneficiary token account must hold the vault mint and belong to the beneficiary.
+            require_keys_eq!(
+                *beneficiary_ata.owner,
+                self.token_program.key(),
+                ValhallaError::InvalidBeneficiaries
+            );
+            let token_account =
+                TokenAccount::try_deserialize(&mut &beneficiary_ata.data.borrow()[..])?;
+            require_keys_eq!(
+                token_account.owner,
+                beneficiary.recipient,
+                ValhallaError::InvalidBeneficiaries
+            );
+            require_keys_eq!(
+                token_account.mint,
+                self.mint.key(),
+                ValhallaError::InvalidBeneficiaries
+            );
+
+            if share > 0 {
+                self.transfer(share, beneficiary_ata.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn transfer(&self, amount: u64, to: AccountInfo<'info>) -> Result<()> {
         let lock_key = self.vault.to_account_info().key();
         let signer: &[&[&[u8]]] = &[&[
             lock_key.as_ref(),
@@ -113,16 +253,25 @@ impl<'info> CancelVault<'info> {
         let cpi_accounts = TransferChecked {
             from: self.vault_ata.to_account_info(),
             mint: self.mint.to_account_info(),
-            to: self.creator_ata.to_account_info(),
+            to,
             authority: self.vault_ata.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
 
-        transfer_checked(cpi_ctx, self.vault_ata.amount, self.mint.decimals)
+        utils::transfer_checked_with_fee(cpi_ctx, amount, self.mint.decimals)?;
+
+        Ok(())
     }
 
     fn close_vault_ata(&self) -> Result<()> {
+        // Withheld transfer fees block closing a Token-2022 account.
+        utils::harvest_withheld_tokens(
+            self.token_program.to_account_info(),
+            self.mint.to_account_info(),
+            self.vault_ata.to_account_info(),
+        )?;
+
         let lock_key = self.vault.to_account_info().key();
         let signer: &[&[&[u8]]] = &[&[
             lock_key.as_ref(),
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::VaultCancelled,
+    state::{AutopayEscrow, Vault},
+    utils, Authority,
+};
+
+#[derive(Accounts)]
+pub struct CancelSolVault<'info> {
+    #[account(mut, constraint = creator.key() == signer.key() || recipient.key() == signer.key())]
+    pub signer: Signer<'info>,
+
+    #[account(mut, constraint = vault.creator == creator.key())]
+    pub creator: SystemAccount<'info>,
+
+    #[account(mut, constraint = vault.recipient == recipient.key())]
+    pub recipient: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = creator,
+        constraint = vault.is_native @ ValhallaError::InvalidVaultType,
+        seeds = [
+            vault.identifier.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            native_mint::ID.as_ref(),
+            constants::VAULT_SEED
+        ],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        bump,
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CancelSolVault<'info> {
+    pub fn cancel(&mut self) -> Result<()> {
+        self.validate_cancel_authority()?;
+
+        // The unused autopay budget is refunded to the creator along with the vault.
+        require!(
+            !self.vault.autopay || self.autopay_escrow.is_some(),
+            ValhallaError::InvalidAutopayEscrow
+        );
+
+        // The recipient keeps everything that has vested so far; the rest is returned to the
+        // creator when the vault is closed.
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let vault_balance = utils::get_native_balance(&self.vault.to_account_info())?;
+        let recipient_amount = match self.vault.is_locked(current_time)? {
+            true => 0,
+            false => self
+                .vault
+                .get_claimable_amount(current_time, vault_balance, None)?,
+        };
+        let creator_amount = vault_balance
+            .checked_sub(recipient_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        if recipient_amount > 0 {
+            self.vault.sub_lamports(recipient_amount)?;
+            self.recipient.add_lamports(recipient_amount)?;
+        }
+
+        emit!(VaultCancelled {
+            vault: self.vault.key(),
+            signer: self.signer.key(),
+            recipient_amount,
+            creator_amount,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    fn validate_cancel_authority(&self) -> Result<()> {
+        match self.vault.cancel_authority {
+            Authority::Neither => {
+                return Err(ValhallaError::Unauthorized.into());
+            }
+            Authority::Creator => {
+                if self.creator.key() != self.signer.key() {
+                    return Err(ValhallaError::Unauthorized.into());
+                }
+            }
+            Authority::Recipient => {
+                if self.recipient.key() != self.signer.key() {
+                    return Err(ValhallaError::Unauthorized.into());
+                }
+            }
+            Authority::Both => {
+                if self.creator.key() != self.signer.key()
+                    && self.recipient.key() != self.signer.key()
+                {
+                    return Err(ValhallaError::Unauthorized.into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -3,7 +3,10 @@ use anchor_spl::token_interface::{
     close_account, CloseAccount, Mint, TokenAccount, TokenInterface,
 };
 
-use crate::{constants, errors::ValhallaError, Vault};
+use crate::{
+    constants, errors::ValhallaError, events::VaultClosed, utils, AutopayEscrow, Beneficiaries,
+    Schedule, Vault,
+};
 
 #[derive(Accounts)]
 pub struct CloseVault<'info> {
@@ -35,6 +38,37 @@ pub struct CloseVault<'info> {
     )]
     pub vault_ata: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            vault.key().as_ref(),
+            constants::SCHEDULE_SEED
+        ],
+        bump,
+    )]
+    pub schedule: Option<Account<'info, Schedule>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [
+            vault.key().as_ref(),
+            constants::BENEFICIARIES_SEED
+        ],
+        bump,
+    )]
+    pub beneficiaries: Option<Account<'info, Beneficiaries>>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        bump,
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
+
+    #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
     pub token_program: Interface<'info, TokenInterface>,
@@ -42,16 +76,39 @@ pub struct CloseVault<'info> {
 
 impl<'info> CloseVault<'info> {
     pub fn close(&mut self) -> Result<()> {
-        match self.vault.is_expired(Clock::get()?.unix_timestamp as u64)? {
-            false => Err(ValhallaError::Locked.into()),
+        // The unused autopay budget is refunded to the creator along with the vault.
+        require!(
+            !self.vault.autopay || self.autopay_escrow.is_some(),
+            ValhallaError::InvalidAutopayEscrow
+        );
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        match self.vault.is_expired(current_time)? {
+            false => return Err(ValhallaError::Locked.into()),
             true => match self.vault_ata.amount {
-                0 => self.close_vault_ata(),
-                _ => Err(ValhallaError::CloseVaultFailed.into()),
+                0 => self.close_vault_ata()?,
+                _ => return Err(ValhallaError::CloseVaultFailed.into()),
             },
         }
+
+        emit!(VaultClosed {
+            vault: self.vault.key(),
+            creator: self.creator.key(),
+            timestamp: current_time,
+        });
+
+        Ok(())
     }
 
     fn close_vault_ata(&mut self) -> Result<()> {
+        // Withheld transfer fees block closing a Token-2022 account.
+        utils::harvest_withheld_tokens(
+            self.token_program.to_account_info(),
+            self.mint.to_account_info(),
+            self.vault_ata.to_account_info(),
+        )?;
+
         let lock_key = self.vault.key();
         let signer_seeds: &[&[&[u8]]] = &[&[
             lock_key.as_ref(),
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::{constants, errors::ValhallaError, events::VaultClosed, utils, AutopayEscrow, Vault};
+
+#[derive(Accounts)]
+pub struct CloseSolVault<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        close = creator,
+        constraint = vault.is_native @ ValhallaError::InvalidVaultType,
+        seeds = [
+            vault.identifier.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            native_mint::ID.as_ref(),
+            constants::VAULT_SEED
+        ],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        bump,
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
+}
+
+impl<'info> CloseSolVault<'info> {
+    pub fn close(&mut self) -> Result<()> {
+        // The unused autopay budget is refunded to the creator along with the vault.
+        require!(
+            !self.vault.autopay || self.autopay_escrow.is_some(),
+            ValhallaError::InvalidAutopayEscrow
+        );
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+
+        match self.vault.is_expired(current_time)? {
+            false => return Err(ValhallaError::Locked.into()),
+            true => match utils::get_native_balance(&self.vault.to_account_info())? {
+                0 => {}
+                _ => return Err(ValhallaError::CloseVaultFailed.into()),
+            },
+        }
+
+        emit!(VaultClosed {
+            vault: self.vault.key(),
+            creator: self.creator.key(),
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+}
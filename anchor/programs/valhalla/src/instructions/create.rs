@@ -1,16 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{
-        mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
-    },
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked},
 };
 use solana_program::{rent::Rent, system_instruction};
 
 use crate::{
     constants,
-    state::{Config, Vault},
-    Authority,
+    errors::ValhallaError,
+    events::VaultCreated,
+    state::{AutopayEscrow, Config, FeeStats, MintFeeOverride, Vault},
+    utils, Authority, VestingMode,
 };
 
 #[derive(Accounts)]
@@ -22,13 +22,7 @@ pub struct CreateVault<'info> {
     #[account(mut)]
     pub recipient: SystemAccount<'info>,
 
-    #[account(mut)]
-    pub dev_treasury: SystemAccount<'info>,
-
-    #[account(mut)]
-    pub dao_treasury: SystemAccount<'info>,
-
-    #[account(seeds = [constants::CONFIG_SEED], bump, has_one = dev_treasury)]
+    #[account(seeds = [constants::CONFIG_SEED], bump)]
     pub config: Box<Account<'info, Config>>,
 
     #[account(
@@ -62,11 +56,35 @@ pub struct CreateVault<'info> {
     #[account(
         init_if_needed,
         payer = creator,
-        associated_token::mint = mint,
-        associated_token::authority = dao_treasury,
-        associated_token::token_program = token_program,
+        seeds = [mint.key().as_ref(), constants::FEE_VAULT_SEED],
+        bump,
+        token::mint = mint,
+        token::authority = fee_vault,
+        token::token_program = token_program,
     )]
-    pub dao_treasury_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [mint.key().as_ref(), constants::FEE_STATS_SEED],
+        bump,
+        space = FeeStats::INIT_SPACE,
+    )]
+    pub fee_stats: Box<Account<'info, FeeStats>>,
+
+    /// CHECK: may not exist, so it is loaded with `MintFeeOverride::try_load`
+    #[account(seeds = [mint.key().as_ref(), constants::MINT_FEE_OVERRIDE_SEED], bump)]
+    pub mint_fee_override: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        space = AutopayEscrow::INIT_SPACE,
+        bump
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
 
     #[account(
         mut,
@@ -115,24 +133,137 @@ impl<'info> CreateVault<'info> {
         total_vesting_duration: u64,
         start_date: u64,
         payout_interval: u64,
+        cliff_date: u64,
+        cliff_amount: u64,
+        vesting_mode: VestingMode,
         cancel_authority: Authority,
+        transfer_authority: Authority,
         autopay: bool,
+        allow_public_deposits: bool,
         bumps: &CreateVaultBumps,
     ) -> Result<()> {
-        let mut deposit_amount = amount_to_be_vested
-            .checked_mul((10u64).pow(self.mint.decimals as u32))
-            .unwrap();
+        let decimals_multiplier = (10u64)
+            .checked_pow(self.mint.decimals as u32)
+            .ok_or(ValhallaError::MathOverflow)?;
+        let amount_to_be_vested = amount_to_be_vested
+            .checked_mul(decimals_multiplier)
+            .ok_or(ValhallaError::MathOverflow)?;
+        let cliff_amount = cliff_amount
+            .checked_mul(decimals_multiplier)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        self.create_with_base_units(
+            identifier,
+            name,
+            amount_to_be_vested,
+            total_vesting_duration,
+            start_date,
+            payout_interval,
+            cliff_date,
+            cliff_amount,
+            vesting_mode,
+            cancel_authority,
+            transfer_authority,
+            autopay,
+            allow_public_deposits,
+            bumps,
+        )
+    }
 
-        let token_fee_amount = deposit_amount
-            .checked_mul(self.config.token_fee_basis_points)
-            .unwrap()
-            .checked_div(constants::MAX_BASIS_POINTS)
-            .unwrap();
+    pub fn create_with_base_units(
+        &mut self,
+        identifier: u64,
+        name: [u8; 32],
+        amount_to_be_vested: u64,
+        total_vesting_duration: u64,
+        start_date: u64,
+        payout_interval: u64,
+        cliff_date: u64,
+        cliff_amount: u64,
+        vesting_mode: VestingMode,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+        allow_public_deposits: bool,
+        bumps: &CreateVaultBumps,
+    ) -> Result<()> {
+        require!(
+            !self.config.is_paused(constants::PAUSE_CREATE),
+            ValhallaError::Paused
+        );
+
+        // Risky mint extensions can make the vesting lock meaningless.
+        require!(
+            self.config.is_mint_allowed(
+                utils::get_mint_extensions(&self.mint.to_account_info())?,
+                self.mint.freeze_authority.is_some(),
+            ),
+            ValhallaError::InvalidMintExtensions
+        );
+
+        // Autopay vaults set aside the lamports to pay whoever disburses them.
+        require!(
+            autopay == self.autopay_escrow.is_some(),
+            ValhallaError::InvalidAutopayEscrow
+        );
 
-        deposit_amount = deposit_amount.checked_sub(token_fee_amount).unwrap();
+        let now = Clock::get()?.unix_timestamp as u64;
+        Vault::validate_vesting_parameters(
+            amount_to_be_vested,
+            start_date,
+            total_vesting_duration,
+            payout_interval,
+            now,
+        )?;
+        Vault::validate_transfer_authority(&transfer_authority)?;
+
+        // Negotiated fees for the mint take precedence over the fees in the config.
+        let (token_fee_basis_points, dev_fee) = self.config.get_mint_fees(
+            MintFeeOverride::try_load(&self.mint_fee_override.to_account_info())?.as_ref(),
+        );
+
+        // Governance token holders get a discount on top of that.
+        let (token_fee_basis_points, dev_fee) = self.config.get_discounted_fees(
+            token_fee_basis_points,
+            dev_fee,
+            self.creator_governance_ata.amount,
+        )?;
+
+        let token_fee_amount =
+            utils::get_token_fee_amount(amount_to_be_vested, token_fee_basis_points)?;
+
+        let deposit_amount = amount_to_be_vested
+            .checked_sub(token_fee_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        // Token-2022 transfer fees are withheld from what the vault token account receives.
+        let received_amount = deposit_amount
+            .checked_sub(utils::get_transfer_fee(
+                &self.mint.to_account_info(),
+                deposit_amount,
+            )?)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        // Scheduled vaults must be created with a schedule.
+        require!(
+            vesting_mode != VestingMode::Scheduled,
+            ValhallaError::InvalidSchedule
+        );
+
+        // The cliff must fall within the vesting period and cannot exceed the deposit.
+        let end_date = start_date
+            .checked_add(total_vesting_duration)
+            .ok_or(ValhallaError::MathOverflow)?;
+        require!(cliff_date <= end_date, ValhallaError::InvalidCliff);
+        require!(cliff_amount <= received_amount, ValhallaError::InvalidCliff);
+
+        // Linear payouts only cover the period after the cliff.
+        let linear_start_date = start_date.max(cliff_date);
+        let linear_vesting_duration = end_date
+            .checked_sub(linear_start_date)
+            .ok_or(ValhallaError::MathOverflow)?;
 
         // Set the vault state.
-        let now = Clock::get()?.unix_timestamp as u64;
         self.vault.set_inner(Vault {
             identifier,
             name,
@@ -142,13 +273,22 @@ impl<'info> CreateVault<'info> {
             total_vesting_duration,
             created_timestamp: now,
             start_date,
-            last_payment_timestamp: now,
-            initial_deposit_amount: deposit_amount,
-            total_number_of_payouts: (total_vesting_duration / payout_interval).max(1),
+            last_payment_timestamp: linear_start_date,
+            initial_deposit_amount: received_amount,
+            total_number_of_payouts: (linear_vesting_duration / payout_interval).max(1),
             payout_interval,
             number_of_payments_made: 0,
+            cliff_date,
+            cliff_amount,
+            is_cliff_payment_disbursed: false,
+            total_withdrawn: 0,
+            vesting_mode,
             cancel_authority,
+            transfer_authority,
             autopay,
+            allow_public_deposits,
+            is_split: false,
+            is_native: false,
             token_account_bump: bumps.vault_ata,
         });
 
@@ -161,17 +301,41 @@ impl<'info> CreateVault<'info> {
             self.mint.to_account_info(),
         )?;
 
-        // Transfer the token fee to the treasury
-        self.transfer(
+        // Transfer the token fee to the fee vault
+        let withheld_fee_amount = self.transfer(
             token_fee_amount,
             self.creator_ata.to_account_info(),
-            self.dao_treasury_ata.to_account_info(),
+            self.fee_vault.to_account_info(),
             self.creator.to_account_info(),
             self.mint.to_account_info(),
         )?;
 
-        // Transfer sol fee to the dev treasury
-        self.transfer_sol()?;
+        // Transfer sol fee to the fee stats account
+        let sol_fee_amount = dev_fee;
+        self.transfer_sol(sol_fee_amount, self.fee_stats.to_account_info())?;
+
+        self.fee_stats.mint = self.mint.key();
+        self.fee_stats.add_token_fees(
+            token_fee_amount
+                .checked_sub(withheld_fee_amount)
+                .ok_or(ValhallaError::MathOverflow)?,
+        )?;
+        self.fee_stats.add_sol_fees(sol_fee_amount)?;
+
+        // Fund the autopay escrow
+        let autopay_amount = self.fund_autopay_escrow()?;
+
+        emit!(VaultCreated {
+            vault: self.vault.key(),
+            creator: self.creator.key(),
+            recipient: self.recipient.key(),
+            mint: self.mint.key(),
+            deposit_amount: received_amount,
+            token_fee_amount,
+            sol_fee_amount,
+            autopay_amount,
+            timestamp: now,
+        });
 
         // Mint governance tokens
         match self.vault.cancel_authority {
@@ -180,38 +344,35 @@ impl<'info> CreateVault<'info> {
         }
     }
 
-    fn transfer_sol(&mut self) -> Result<()> {
-        let from = self.creator.to_account_info();
-        let to = self.dev_treasury.to_account_info();
-
-        let fee = if self.vault.autopay {
-            let clock_fee_per_tx: u64 = 1000; // 1000 lamports per tx for clockwork
-            let token_account_creation_fee: u64 = self.creator_ata.get_lamports();
-            let max_tx_needed = self.vault.total_number_of_payouts;
-            let total_fee = clock_fee_per_tx
-                .checked_add(token_account_creation_fee)
-                .unwrap();
-
-            total_fee
-                .checked_mul(max_tx_needed)
-                .unwrap()
-                .checked_mul(self.config.autopay_multiplier)
-                .unwrap()
-        } else {
-            self.config.dev_fee
+    fn fund_autopay_escrow(&mut self) -> Result<u64> {
+        let lamports_per_disburse = self
+            .config
+            .get_autopay_lamports_per_disburse(self.creator_ata.get_lamports())?;
+        let autopay_amount = lamports_per_disburse
+            .checked_mul(self.vault.total_number_of_payouts)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        let vault = self.vault.key();
+        let autopay_escrow = match &mut self.autopay_escrow {
+            Some(autopay_escrow) => autopay_escrow,
+            None => return Ok(0),
         };
+        autopay_escrow.vault = vault;
+        autopay_escrow.lamports_per_disburse = lamports_per_disburse;
+        let to = autopay_escrow.to_account_info();
+
+        self.transfer_sol(autopay_amount, to)?;
 
-        let transfer_ix = system_instruction::transfer(from.key, to.key, fee);
+        Ok(autopay_amount)
+    }
+
+    fn transfer_sol(&self, amount: u64, to: AccountInfo<'info>) -> Result<()> {
+        let from = self.creator.to_account_info();
+        let transfer_ix = system_instruction::transfer(from.key, to.key, amount);
 
-        solana_program::program::invoke_signed(
+        solana_program::program::invoke(
             &transfer_ix,
-            &[
-                from,
-                to,
-                self.creator.to_account_info(),
-                self.system_program.to_account_info(),
-            ],
-            &[],
+            &[from, to, self.system_program.to_account_info()],
         )?;
 
         Ok(())
@@ -224,7 +385,7 @@ impl<'info> CreateVault<'info> {
         to: AccountInfo<'info>,
         authority: AccountInfo<'info>,
         mint: AccountInfo<'info>,
-    ) -> Result<()> {
+    ) -> Result<u64> {
         let cpi_program = self.token_program.to_account_info();
         let cpi_accounts = TransferChecked {
             from,
@@ -234,7 +395,7 @@ impl<'info> CreateVault<'info> {
         };
         let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
 
-        transfer_checked(cpi_ctx, amount, self.mint.decimals)
+        utils::transfer_checked_with_fee(cpi_ctx, amount, self.mint.decimals)
     }
 
     fn mint_governance_tokens(&self, bumps: &CreateVaultBumps) -> Result<()> {
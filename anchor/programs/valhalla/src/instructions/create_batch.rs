@@ -0,0 +1,531 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{allocate, assign, create_account, Allocate, Assign, CreateAccount},
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::spl_token_2022::{
+        self,
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    },
+    token_interface::{
+        initialize_account3, mint_to, InitializeAccount3, Mint, MintTo, TokenAccount,
+        TokenInterface, TransferChecked,
+    },
+};
+use solana_program::system_instruction;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::VaultCreated,
+    state::{AutopayEscrow, Config, FeeStats, MintFeeOverride, Vault},
+    utils, Authority, BatchVault, VestingMode,
+};
+
+/// The number of remaining accounts passed per vault: recipient, vault and vault token account,
+/// followed by the autopay escrow when the batch uses autopay.
+const ACCOUNTS_PER_VAULT: usize = 3;
+
+#[derive(Accounts)]
+pub struct CreateBatchVault<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(seeds = [constants::CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [mint.key().as_ref(), constants::FEE_VAULT_SEED],
+        bump,
+        token::mint = mint,
+        token::authority = fee_vault,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [mint.key().as_ref(), constants::FEE_STATS_SEED],
+        bump,
+        space = FeeStats::INIT_SPACE,
+    )]
+    pub fee_stats: Box<Account<'info, FeeStats>>,
+
+    /// CHECK: may not exist, so it is loaded with `MintFeeOverride::try_load`
+    #[account(seeds = [mint.key().as_ref(), constants::MINT_FEE_OVERRIDE_SEED], bump)]
+    pub mint_fee_override: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = governance_token_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = governance_token_program,
+    )]
+    pub creator_governance_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        mint::decimals = 9,
+        mint::authority = governance_token_mint,
+        mint::token_program = governance_token_program,
+        seeds = [constants::GOVERNANCE_TOKEN_MINT_SEED],
+        bump,
+    )]
+    pub governance_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub governance_token_program: Interface<'info, TokenInterface>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateBatchVault<'info> {
+    pub fn create(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        name: [u8; 32],
+        vaults: Vec<BatchVault>,
+        total_vesting_duration: u64,
+        start_date: u64,
+        payout_interval: u64,
+        cliff_date: u64,
+        vesting_mode: VestingMode,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+        allow_public_deposits: bool,
+        bumps: &CreateBatchVaultBumps,
+    ) -> Result<()> {
+        let accounts_per_vault = ACCOUNTS_PER_VAULT + autopay as usize;
+        require!(!vaults.is_empty(), ValhallaError::InvalidBatch);
+        require!(
+            remaining_accounts.len() == vaults.len() * accounts_per_vault,
+            ValhallaError::InvalidBatch
+        );
+
+        require!(
+            !self.config.is_paused(constants::PAUSE_CREATE),
+            ValhallaError::Paused
+        );
+
+        // Risky mint extensions can make the vesting lock meaningless.
+        require!(
+            self.config.is_mint_allowed(
+                utils::get_mint_extensions(&self.mint.to_account_info())?,
+                self.mint.freeze_authority.is_some(),
+            ),
+            ValhallaError::InvalidMintExtensions
+        );
+
+        // Scheduled vaults must be created with a schedule.
+        require!(
+            vesting_mode != VestingMode::Scheduled,
+            ValhallaError::InvalidSchedule
+        );
+
+        Vault::validate_transfer_authority(&transfer_authority)?;
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        for batch_vault in &vaults {
+            Vault::validate_vesting_parameters(
+                batch_vault.amount_to_be_vested,
+                start_date,
+                total_vesting_duration,
+                payout_interval,
+                now,
+            )?;
+        }
+
+        // Linear payouts only cover the period after the cliff.
+        let end_date = start_date
+            .checked_add(total_vesting_duration)
+            .ok_or(ValhallaError::MathOverflow)?;
+        require!(cliff_date <= end_date, ValhallaError::InvalidCliff);
+        let linear_start_date = start_date.max(cliff_date);
+        let linear_vesting_duration = end_date
+            .checked_sub(linear_start_date)
+            .ok_or(ValhallaError::MathOverflow)?;
+        let total_number_of_payouts = (linear_vesting_duration / payout_interval).max(1);
+
+        let decimals_multiplier = (10u64)
+            .checked_pow(self.mint.decimals as u32)
+            .ok_or(ValhallaError::MathOverflow)?;
+        // Negotiated fees for the mint take precedence over the fees in the config.
+        let (token_fee_basis_points, dev_fee) = self.config.get_mint_fees(
+            MintFeeOverride::try_load(&self.mint_fee_override.to_account_info())?.as_ref(),
+        );
+
+        // Governance token holders get a discount on top of that.
+        let (token_fee_basis_points, dev_fee) = self.config.get_discounted_fees(
+            token_fee_basis_points,
+            dev_fee,
+            self.creator_governance_ata.amount,
+        )?;
+
+        let lamports_per_disburse = self
+            .config
+            .get_autopay_lamports_per_disburse(self.creator_ata.get_lamports())?;
+        let mut total_token_fee_amount: u64 = 0;
+        let mut created_events = Vec::with_capacity(vaults.len());
+
+        for (batch_vault, accounts) in vaults
+            .iter()
+            .zip(remaining_accounts.chunks(accounts_per_vault))
+        {
+            let (recipient, vault, vault_ata) = (&accounts[0], &accounts[1], &accounts[2]);
+            require_keys_eq!(*recipient.owner, System::id(), ValhallaError::InvalidBatch);
+
+            let mut deposit_amount = batch_vault
+                .amount_to_be_vested
+                .checked_mul(decimals_multiplier)
+                .ok_or(ValhallaError::MathOverflow)?;
+
+            let token_fee_amount =
+                utils::get_token_fee_amount(deposit_amount, token_fee_basis_points)?;
+
+            deposit_amount = deposit_amount
+                .checked_sub(token_fee_amount)
+                .ok_or(ValhallaError::MathOverflow)?;
+            total_token_fee_amount = total_token_fee_amount
+                .checked_add(token_fee_amount)
+                .ok_or(ValhallaError::MathOverflow)?;
+
+            // Token-2022 transfer fees are withheld from what the vault token account receives.
+            let received_amount = deposit_amount
+                .checked_sub(utils::get_transfer_fee(
+                    &self.mint.to_account_info(),
+                    deposit_amount,
+                )?)
+                .ok_or(ValhallaError::MathOverflow)?;
+
+            let cliff_amount = batch_vault
+                .cliff_amount
+                .checked_mul(decimals_multiplier)
+                .ok_or(ValhallaError::MathOverflow)?;
+            require!(cliff_amount <= received_amount, ValhallaError::InvalidCliff);
+
+            let token_account_bump = self.create_vault_ata(vault, vault_ata)?;
+
+            self.create_vault(
+                vault,
+                batch_vault.identifier,
+                Vault {
+                    identifier: batch_vault.identifier,
+                    name,
+                    creator: self.creator.key(),
+                    recipient: recipient.key(),
+                    mint: self.mint.key(),
+                    total_vesting_duration,
+                    created_timestamp: now,
+                    start_date,
+                    last_payment_timestamp: linear_start_date,
+                    initial_deposit_amount: received_amount,
+                    total_number_of_payouts,
+                    payout_interval,
+                    number_of_payments_made: 0,
+                    cliff_date,
+                    cliff_amount,
+                    is_cliff_payment_disbursed: false,
+                    total_withdrawn: 0,
+                    vesting_mode: vesting_mode.clone(),
+                    cancel_authority: cancel_authority.clone(),
+                    transfer_authority: transfer_authority.clone(),
+                    autopay,
+                    allow_public_deposits,
+                    is_split: false,
+                    is_native: false,
+                    token_account_bump,
+                },
+            )?;
+
+            // Transfer the amount to the vault token account
+            self.transfer(deposit_amount, vault_ata.clone())?;
+
+            // Fund the autopay escrow
+            let autopay_amount = match autopay {
+                true => self.create_autopay_escrow(
+                    vault,
+                    &accounts[3],
+                    lamports_per_disburse,
+                    total_number_of_payouts,
+                )?,
+                false => 0,
+            };
+
+            created_events.push(VaultCreated {
+                vault: vault.key(),
+                creator: self.creator.key(),
+                recipient: recipient.key(),
+                mint: self.mint.key(),
+                deposit_amount: received_amount,
+                token_fee_amount,
+                sol_fee_amount: 0,
+                autopay_amount,
+                timestamp: now,
+            });
+        }
+
+        // Transfer the token fee on the whole batch to the fee vault
+        let withheld_fee_amount =
+            self.transfer(total_token_fee_amount, self.fee_vault.to_account_info())?;
+
+        // Transfer sol fee to the fee stats account
+        let sol_fee_amount = dev_fee;
+        self.transfer_sol(sol_fee_amount, self.fee_stats.to_account_info())?;
+
+        self.fee_stats.mint = self.mint.key();
+        self.fee_stats.add_token_fees(
+            total_token_fee_amount
+                .checked_sub(withheld_fee_amount)
+                .ok_or(ValhallaError::MathOverflow)?,
+        )?;
+        self.fee_stats.add_sol_fees(sol_fee_amount)?;
+
+        // The sol fee is charged once per batch, so it is only attributed to the first vault.
+        for (index, mut event) in created_events.into_iter().enumerate() {
+            if index == 0 {
+                event.sol_fee_amount = sol_fee_amount;
+            }
+            emit!(event);
+        }
+
+        // Mint governance tokens
+        match cancel_authority {
+            Authority::Neither => self.mint_governance_tokens(bumps),
+            _ => Ok(()),
+        }
+    }
+
+    fn create_vault(&self, vault: &AccountInfo<'info>, identifier: u64, data: Vault) -> Result<()> {
+        let identifier_bytes = identifier.to_le_bytes();
+        let creator_key = self.creator.key();
+        let mint_key = self.mint.key();
+        let (vault_key, vault_bump) = Pubkey::find_program_address(
+            &[
+                identifier_bytes.as_ref(),
+                creator_key.as_ref(),
+                mint_key.as_ref(),
+                constants::VAULT_SEED,
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(vault_key, vault.key(), ValhallaError::InvalidBatch);
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            identifier_bytes.as_ref(),
+            creator_key.as_ref(),
+            mint_key.as_ref(),
+            constants::VAULT_SEED,
+            &[vault_bump],
+        ]];
+
+        self.create_account(vault, Vault::INIT_SPACE, &crate::ID, signer_seeds)?;
+
+        let mut vault_data = vault.try_borrow_mut_data()?;
+        data.try_serialize(&mut &mut vault_data[..])
+    }
+
+    fn create_vault_ata(
+        &self,
+        vault: &AccountInfo<'info>,
+        vault_ata: &AccountInfo<'info>,
+    ) -> Result<u8> {
+        let vault_key = vault.key();
+        let (vault_ata_key, vault_ata_bump) = Pubkey::find_program_address(
+            &[vault_key.as_ref(), constants::VAULT_ATA_SEED],
+            &crate::ID,
+        );
+        require_keys_eq!(vault_ata_key, vault_ata.key(), ValhallaError::InvalidBatch);
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            vault_key.as_ref(),
+            constants::VAULT_ATA_SEED,
+            &[vault_ata_bump],
+        ]];
+
+        self.create_account(
+            vault_ata,
+            self.get_token_account_space()?,
+            &self.token_program.key(),
+            signer_seeds,
+        )?;
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = InitializeAccount3 {
+            account: vault_ata.clone(),
+            mint: self.mint.to_account_info(),
+            authority: vault_ata.clone(),
+        };
+        initialize_account3(CpiContext::new(cpi_program, cpi_accounts))?;
+
+        Ok(vault_ata_bump)
+    }
+
+    fn create_autopay_escrow(
+        &self,
+        vault: &AccountInfo<'info>,
+        autopay_escrow: &AccountInfo<'info>,
+        lamports_per_disburse: u64,
+        total_number_of_payouts: u64,
+    ) -> Result<u64> {
+        let vault_key = vault.key();
+        let (autopay_escrow_key, autopay_escrow_bump) = Pubkey::find_program_address(
+            &[vault_key.as_ref(), constants::AUTOPAY_ESCROW_SEED],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            autopay_escrow_key,
+            autopay_escrow.key(),
+            ValhallaError::InvalidAutopayEscrow
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            vault_key.as_ref(),
+            constants::AUTOPAY_ESCROW_SEED,
+            &[autopay_escrow_bump],
+        ]];
+
+        self.create_account(
+            autopay_escrow,
+            AutopayEscrow::INIT_SPACE,
+            &crate::ID,
+            signer_seeds,
+        )?;
+
+        {
+            let mut autopay_escrow_data = autopay_escrow.try_borrow_mut_data()?;
+            AutopayEscrow {
+                vault: vault_key,
+                lamports_per_disburse,
+                number_of_payouts_paid: 0,
+            }
+            .try_serialize(&mut &mut autopay_escrow_data[..])?;
+        }
+
+        let autopay_amount = lamports_per_disburse
+            .checked_mul(total_number_of_payouts)
+            .ok_or(ValhallaError::MathOverflow)?;
+        self.transfer_sol(autopay_amount, autopay_escrow.clone())?;
+
+        Ok(autopay_amount)
+    }
+
+    fn get_token_account_space(&self) -> Result<usize> {
+        let mint_info = self.mint.to_account_info();
+        if *mint_info.owner != spl_token_2022::ID {
+            return Ok(anchor_spl::token::TokenAccount::LEN);
+        }
+
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        let mint_extensions = mint_state.get_extension_types()?;
+        let required_extensions =
+            ExtensionType::get_required_init_account_extensions(&mint_extensions);
+
+        Ok(ExtensionType::try_calculate_account_len::<
+            spl_token_2022::state::Account,
+        >(&required_extensions)?)
+    }
+
+    fn create_account(
+        &self,
+        account: &AccountInfo<'info>,
+        space: usize,
+        owner: &Pubkey,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let lamports = Rent::get()?.minimum_balance(space);
+        let cpi_program = self.system_program.to_account_info();
+
+        if account.lamports() == 0 {
+            let cpi_accounts = CreateAccount {
+                from: self.creator.to_account_info(),
+                to: account.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+            return create_account(cpi_ctx, lamports, space as u64, owner);
+        }
+
+        // Anyone can send lamports to an address before it is created, which makes
+        // `create_account` fail. Like Anchor's `init`, top up the rent and then allocate and
+        // assign the account instead.
+        let shortfall = lamports.saturating_sub(account.lamports());
+        if shortfall > 0 {
+            self.transfer_sol(shortfall, account.clone())?;
+        }
+
+        let cpi_accounts = Allocate {
+            account_to_allocate: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer_seeds);
+        allocate(cpi_ctx, space as u64)?;
+
+        let cpi_accounts = Assign {
+            account_to_assign: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        assign(cpi_ctx, owner)
+    }
+
+    fn transfer_sol(&self, amount: u64, to: AccountInfo<'info>) -> Result<()> {
+        let from = self.creator.to_account_info();
+        let transfer_ix = system_instruction::transfer(from.key, to.key, amount);
+
+        solana_program::program::invoke(
+            &transfer_ix,
+            &[from, to, self.system_program.to_account_info()],
+        )?;
+
+        Ok(())
+    }
+
+    fn transfer(&self, amount: u64, to: AccountInfo<'info>) -> Result<u64> {
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.creator_ata.to_account_info(),
+            to,
+            authority: self.creator.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        utils::transfer_checked_with_fee(cpi_ctx, amount, self.mint.decimals)
+    }
+
+    fn mint_governance_tokens(&self, bumps: &CreateBatchVaultBumps) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            constants::GOVERNANCE_TOKEN_MINT_SEED,
+            &[bumps.governance_token_mint],
+        ]];
+
+        let cpi_program = self.governance_token_program.to_account_info();
+        let cpi_accounts = MintTo {
+            mint: self.governance_token_mint.to_account_info(),
+            to: self.creator_governance_ata.to_account_info(),
+            authority: self.governance_token_mint.to_account_info(),
+        };
+        let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        mint_to(cpi_context, self.config.governance_token_amount)
+    }
+}
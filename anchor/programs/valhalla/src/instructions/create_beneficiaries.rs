@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::VaultSplit,
+    state::{Beneficiaries, Vault},
+    Authority, Beneficiary,
+};
+
+#[derive(Accounts)]
+pub struct CreateBeneficiaries<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(constraint = vault.recipient == recipient.key())]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator,
+        seeds = [
+            vault.identifier.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            vault.mint.as_ref(),
+            constants::VAULT_SEED
+        ],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            vault.key().as_ref(),
+            constants::BENEFICIARIES_SEED
+        ],
+        space = Beneficiaries::INIT_SPACE,
+        bump
+    )]
+    pub beneficiaries: Box<Account<'info, Beneficiaries>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateBeneficiaries<'info> {
+    pub fn create(&mut self, beneficiaries: Vec<Beneficiary>) -> Result<()> {
+        // Native vaults pay out lamports, which are not split.
+        require!(!self.vault.is_native, ValhallaError::InvalidVaultType);
+
+        // Vaults that cannot be cancelled are irrevocable, so their payouts cannot be redirected
+        // either.
+        require!(
+            self.vault.cancel_authority != Authority::Neither,
+            ValhallaError::Unauthorized
+        );
+
+        // The split can only be set up before anything has been paid out.
+        require!(
+            self.vault.total_withdrawn == 0,
+            ValhallaError::InvalidBeneficiaries
+        );
+        self.validate_beneficiaries(&beneficiaries)?;
+
+        self.vault.is_split = true;
+        self.beneficiaries.set_inner(Beneficiaries {
+            vault: self.vault.key(),
+            beneficiaries,
+        });
+
+        emit!(VaultSplit {
+            vault: self.vault.key(),
+            number_of_beneficiaries: self.beneficiaries.beneficiaries.len() as u64,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+
+    fn validate_beneficiaries(&self, beneficiaries: &[Beneficiary]) -> Result<()> {
+        require!(
+            !beneficiaries.is_empty() && beneficiaries.len() as u64 <= constants::MAX_BENEFICIARIES,
+            ValhallaError::InvalidBeneficiaries
+        );
+
+        // Every beneficiary must have a share and the shares must add up to the whole payout.
+        require!(
+            beneficiaries
+                .iter()
+                .all(|beneficiary| beneficiary.share_basis_points > 0),
+            ValhallaError::InvalidBeneficiaries
+        );
+        let total_share_basis_points = beneficiaries.iter().try_fold(0u64, |total, beneficiary| {
+            total.checked_add(beneficiary.share_basis_points)
+        });
+        require!(
+            total_share_basis_points == Some(constants::MAX_BASIS_POINTS),
+            ValhallaError::InvalidBeneficiaries
+        );
+
+        Ok(())
+    }
+}
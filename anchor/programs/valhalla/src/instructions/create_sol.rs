@@ -0,0 +1,344 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::spl_token::native_mint,
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+};
+use solana_program::{native_token::LAMPORTS_PER_SOL, system_instruction};
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::VaultCreated,
+    state::{AutopayEscrow, Config, FeeStats, MintFeeOverride, Vault},
+    utils, Authority, VestingMode,
+};
+
+#[derive(Accounts)]
+#[instruction(identifier: u64)]
+pub struct CreateSolVault<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub recipient: SystemAccount<'info>,
+
+    #[account(seeds = [constants::CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [constants::NATIVE_FEE_STATS_SEED],
+        bump,
+        space = FeeStats::INIT_SPACE,
+    )]
+    pub fee_stats: Box<Account<'info, FeeStats>>,
+
+    /// CHECK: may not exist, so it is loaded with `MintFeeOverride::try_load`
+    #[account(seeds = [native_mint::ID.as_ref(), constants::MINT_FEE_OVERRIDE_SEED], bump)]
+    pub mint_fee_override: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            identifier.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            native_mint::ID.as_ref(),
+            constants::VAULT_SEED,
+        ],
+        space = Vault::INIT_SPACE,
+        bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        space = AutopayEscrow::INIT_SPACE,
+        bump
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = governance_token_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = governance_token_program,
+    )]
+    pub creator_governance_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        mint::decimals = 9,
+        mint::authority = governance_token_mint,
+        mint::token_program = governance_token_program,
+        seeds = [constants::GOVERNANCE_TOKEN_MINT_SEED],
+        bump,
+    )]
+    pub governance_token_mint: InterfaceAccount<'info, Mint>,
+
+    pub governance_token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSolVault<'info> {
+    pub fn create(
+        &mut self,
+        identifier: u64,
+        name: [u8; 32],
+        amount_to_be_vested: u64,
+        total_vesting_duration: u64,
+        start_date: u64,
+        payout_interval: u64,
+        cliff_date: u64,
+        cliff_amount: u64,
+        vesting_mode: VestingMode,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+        bumps: &CreateSolVaultBumps,
+    ) -> Result<()> {
+        let amount_to_be_vested = amount_to_be_vested
+            .checked_mul(LAMPORTS_PER_SOL)
+            .ok_or(ValhallaError::MathOverflow)?;
+        let cliff_amount = cliff_amount
+            .checked_mul(LAMPORTS_PER_SOL)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        self.create_with_lamports(
+            identifier,
+            name,
+            amount_to_be_vested,
+            total_vesting_duration,
+            start_date,
+            payout_interval,
+            cliff_date,
+            cliff_amount,
+            vesting_mode,
+            cancel_authority,
+            transfer_authority,
+            autopay,
+            bumps,
+        )
+    }
+
+    pub fn create_with_lamports(
+        &mut self,
+        identifier: u64,
+        name: [u8; 32],
+        amount_to_be_vested: u64,
+        total_vesting_duration: u64,
+        start_date: u64,
+        payout_interval: u64,
+        cliff_date: u64,
+        cliff_amount: u64,
+        vesting_mode: VestingMode,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+        bumps: &CreateSolVaultBumps,
+    ) -> Result<()> {
+        require!(
+            !self.config.is_paused(constants::PAUSE_CREATE),
+            ValhallaError::Paused
+        );
+
+        // Autopay vaults set aside the lamports to pay whoever disburses them.
+        require!(
+            autopay == self.autopay_escrow.is_some(),
+            ValhallaError::InvalidAutopayEscrow
+        );
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        Vault::validate_vesting_parameters(
+            amount_to_be_vested,
+            start_date,
+            total_vesting_duration,
+            payout_interval,
+            now,
+        )?;
+        Vault::validate_transfer_authority(&transfer_authority)?;
+
+        let mut deposit_amount = amount_to_be_vested;
+
+        // Negotiated fees for the mint take precedence over the fees in the config.
+        let (token_fee_basis_points, dev_fee) = self.config.get_mint_fees(
+            MintFeeOverride::try_load(&self.mint_fee_override.to_account_info())?.as_ref(),
+        );
+
+        // Governance token holders get a discount on top of that.
+        let (token_fee_basis_points, dev_fee) = self.config.get_discounted_fees(
+            token_fee_basis_points,
+            dev_fee,
+            self.creator_governance_ata.amount,
+        )?;
+
+        let token_fee_amount = utils::get_token_fee_amount(deposit_amount, token_fee_basis_points)?;
+
+        deposit_amount = deposit_amount
+            .checked_sub(token_fee_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        // Scheduled vaults must be created with a schedule.
+        require!(
+            vesting_mode != VestingMode::Scheduled,
+            ValhallaError::InvalidSchedule
+        );
+
+        // The cliff must fall within the vesting period and cannot exceed the deposit.
+        let end_date = start_date
+            .checked_add(total_vesting_duration)
+            .ok_or(ValhallaError::MathOverflow)?;
+        require!(cliff_date <= end_date, ValhallaError::InvalidCliff);
+        require!(cliff_amount <= deposit_amount, ValhallaError::InvalidCliff);
+
+        // Linear payouts only cover the period after the cliff.
+        let linear_start_date = start_date.max(cliff_date);
+        let linear_vesting_duration = end_date
+            .checked_sub(linear_start_date)
+            .ok_or(ValhallaError::MathOverflow)?;
+        let total_number_of_payouts = (linear_vesting_duration / payout_interval).max(1);
+
+        // Payouts are paid straight into the recipient's wallet, and the runtime rejects a
+        // transfer that leaves a new wallet below the rent-exempt minimum.
+        let amount_per_payout = deposit_amount
+            .checked_sub(cliff_amount)
+            .and_then(|linear_amount| linear_amount.checked_div(total_number_of_payouts))
+            .ok_or(ValhallaError::MathOverflow)?;
+        require!(
+            amount_per_payout >= Rent::get()?.minimum_balance(0),
+            ValhallaError::InvalidAmount
+        );
+
+        // Set the vault state.
+        self.vault.set_inner(Vault {
+            identifier,
+            name,
+            creator: self.creator.key(),
+            recipient: self.recipient.key(),
+            mint: native_mint::ID,
+            total_vesting_duration,
+            created_timestamp: now,
+            start_date,
+            last_payment_timestamp: linear_start_date,
+            initial_deposit_amount: deposit_amount,
+            total_number_of_payouts,
+            payout_interval,
+            number_of_payments_made: 0,
+            cliff_date,
+            cliff_amount,
+            is_cliff_payment_disbursed: false,
+            total_withdrawn: 0,
+            vesting_mode,
+            cancel_authority,
+            transfer_authority,
+            autopay,
+            allow_public_deposits: false,
+            is_split: false,
+            is_native: true,
+            token_account_bump: 0,
+        });
+
+        // Transfer the amount to the vault, on top of its rent-exempt balance
+        self.transfer_sol(
+            deposit_amount,
+            self.creator.to_account_info(),
+            self.vault.to_account_info(),
+        )?;
+
+        // Both fees are held as lamports in the fee stats account until they are withdrawn
+        let sol_fee_amount = dev_fee;
+        self.transfer_sol(
+            token_fee_amount
+                .checked_add(sol_fee_amount)
+                .ok_or(ValhallaError::MathOverflow)?,
+            self.creator.to_account_info(),
+            self.fee_stats.to_account_info(),
+        )?;
+
+        self.fee_stats.mint = native_mint::ID;
+        self.fee_stats.add_token_fees(token_fee_amount)?;
+        self.fee_stats.add_sol_fees(sol_fee_amount)?;
+
+        // Fund the autopay escrow
+        let autopay_amount = self.fund_autopay_escrow()?;
+
+        emit!(VaultCreated {
+            vault: self.vault.key(),
+            creator: self.creator.key(),
+            recipient: self.recipient.key(),
+            mint: native_mint::ID,
+            deposit_amount,
+            token_fee_amount,
+            sol_fee_amount,
+            autopay_amount,
+            timestamp: now,
+        });
+
+        // Mint governance tokens
+        match self.vault.cancel_authority {
+            Authority::Neither => self.mint_governance_tokens(bumps),
+            _ => Ok(()),
+        }
+    }
+
+    fn fund_autopay_escrow(&mut self) -> Result<u64> {
+        // SOL payouts do not need token accounts, so only the transaction is paid for.
+        let lamports_per_disburse = self.config.get_autopay_lamports_per_disburse(0)?;
+        let autopay_amount = lamports_per_disburse
+            .checked_mul(self.vault.total_number_of_payouts)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        let vault = self.vault.key();
+        let autopay_escrow = match &mut self.autopay_escrow {
+            Some(autopay_escrow) => autopay_escrow,
+            None => return Ok(0),
+        };
+        autopay_escrow.vault = vault;
+        autopay_escrow.lamports_per_disburse = lamports_per_disburse;
+        let to = autopay_escrow.to_account_info();
+
+        self.transfer_sol(autopay_amount, self.creator.to_account_info(), to)?;
+
+        Ok(autopay_amount)
+    }
+
+    fn transfer_sol(
+        &self,
+        amount: u64,
+        from: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+    ) -> Result<()> {
+        let transfer_ix = system_instruction::transfer(from.key, to.key, amount);
+
+        solana_program::program::invoke(
+            &transfer_ix,
+            &[from, to, self.system_program.to_account_info()],
+        )?;
+
+        Ok(())
+    }
+
+    fn mint_governance_tokens(&self, bumps: &CreateSolVaultBumps) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            constants::GOVERNANCE_TOKEN_MINT_SEED,
+            &[bumps.governance_token_mint],
+        ]];
+
+        let cpi_program = self.governance_token_program.to_account_info();
+        let cpi_accounts = MintTo {
+            mint: self.governance_token_mint.to_account_info(),
+            to: self.creator_governance_ata.to_account_info(),
+            authority: self.governance_token_mint.to_account_info(),
+        };
+        let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        mint_to(cpi_context, self.config.governance_token_amount)
+    }
+}
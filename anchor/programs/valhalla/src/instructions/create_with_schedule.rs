@@ -0,0 +1,429 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked},
+};
+use solana_program::{rent::Rent, system_instruction};
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::VaultCreated,
+    state::{AutopayEscrow, Config, FeeStats, MintFeeOverride, Schedule, Vault},
+    utils, Authority, Tranche, VestingMode,
+};
+
+#[derive(Accounts)]
+#[instruction(identifier: u64)]
+pub struct CreateVaultWithSchedule<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
+    #[account(seeds = [constants::CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            identifier.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            mint.key().as_ref(),
+            constants::VAULT_SEED,
+        ],
+        space = Vault::INIT_SPACE,
+        bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [
+            vault.key().as_ref(),
+            constants::VAULT_ATA_SEED
+        ],
+        bump,
+        payer = creator,
+        token::mint = mint,
+        token::authority = vault_ata,
+        token::token_program = token_program,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [
+            vault.key().as_ref(),
+            constants::SCHEDULE_SEED
+        ],
+        space = Schedule::INIT_SPACE,
+        bump
+    )]
+    pub schedule: Box<Account<'info, Schedule>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        space = AutopayEscrow::INIT_SPACE,
+        bump
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [mint.key().as_ref(), constants::FEE_VAULT_SEED],
+        bump,
+        token::mint = mint,
+        token::authority = fee_vault,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        seeds = [mint.key().as_ref(), constants::FEE_STATS_SEED],
+        bump,
+        space = FeeStats::INIT_SPACE,
+    )]
+    pub fee_stats: Box<Account<'info, FeeStats>>,
+
+    /// CHECK: may not exist, so it is loaded with `MintFeeOverride::try_load`
+    #[account(seeds = [mint.key().as_ref(), constants::MINT_FEE_OVERRIDE_SEED], bump)]
+    pub mint_fee_override: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        associated_token::mint = governance_token_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = governance_token_program,
+    )]
+    pub creator_governance_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        mint::decimals = 9,
+        mint::authority = governance_token_mint,
+        mint::token_program = governance_token_program,
+        seeds = [constants::GOVERNANCE_TOKEN_MINT_SEED],
+        bump,
+    )]
+    pub governance_token_mint: InterfaceAccount<'info, Mint>,
+
+    pub governance_token_program: Interface<'info, TokenInterface>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateVaultWithSchedule<'info> {
+    pub fn create(
+        &mut self,
+        identifier: u64,
+        name: [u8; 32],
+        tranches: Vec<Tranche>,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+        allow_public_deposits: bool,
+        bumps: &CreateVaultWithScheduleBumps,
+    ) -> Result<()> {
+        require!(
+            !self.config.is_paused(constants::PAUSE_CREATE),
+            ValhallaError::Paused
+        );
+
+        // Risky mint extensions can make the vesting lock meaningless.
+        require!(
+            self.config.is_mint_allowed(
+                utils::get_mint_extensions(&self.mint.to_account_info())?,
+                self.mint.freeze_authority.is_some(),
+            ),
+            ValhallaError::InvalidMintExtensions
+        );
+
+        // Autopay vaults set aside the lamports to pay whoever disburses them.
+        require!(
+            autopay == self.autopay_escrow.is_some(),
+            ValhallaError::InvalidAutopayEscrow
+        );
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        self.validate_tranches(&tranches, now)?;
+        Vault::validate_transfer_authority(&transfer_authority)?;
+
+        let decimals_multiplier = (10u64)
+            .checked_pow(self.mint.decimals as u32)
+            .ok_or(ValhallaError::MathOverflow)?;
+        let tranches = tranches
+            .into_iter()
+            .map(|tranche| {
+                Ok(Tranche {
+                    timestamp: tranche.timestamp,
+                    amount: tranche
+                        .amount
+                        .checked_mul(decimals_multiplier)
+                        .ok_or(ValhallaError::MathOverflow)?,
+                })
+            })
+            .collect::<Result<Vec<Tranche>>>()?;
+
+        // The tranche amounts are exact, so the token fee is charged on top of them.
+        let deposit_amount = tranches
+            .iter()
+            .try_fold(0u64, |total, tranche| total.checked_add(tranche.amount))
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        // Negotiated fees for the mint take precedence over the fees in the config.
+        let (token_fee_basis_points, dev_fee) = self.config.get_mint_fees(
+            MintFeeOverride::try_load(&self.mint_fee_override.to_account_info())?.as_ref(),
+        );
+
+        // Governance token holders get a discount on top of that.
+        let (token_fee_basis_points, dev_fee) = self.config.get_discounted_fees(
+            token_fee_basis_points,
+            dev_fee,
+            self.creator_governance_ata.amount,
+        )?;
+
+        let token_fee_amount = utils::get_token_fee_amount(deposit_amount, token_fee_basis_points)?;
+
+        // Token-2022 transfer fees are withheld from what the vault token account receives, so the
+        // shortfall is taken off the last tranches.
+        let transfer_fee_amount =
+            utils::get_transfer_fee(&self.mint.to_account_info(), deposit_amount)?;
+        let received_amount = deposit_amount
+            .checked_sub(transfer_fee_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+        let tranches = Self::deduct_from_tranches(tranches, transfer_fee_amount)?;
+
+        let (start_date, end_date) = match (tranches.first(), tranches.last()) {
+            (Some(first), Some(last)) => (first.timestamp, last.timestamp),
+            _ => return Err(ValhallaError::InvalidSchedule.into()),
+        };
+
+        // Set the vault state.
+        self.vault.set_inner(Vault {
+            identifier,
+            name,
+            creator: self.creator.key(),
+            recipient: self.recipient.key(),
+            mint: self.mint.key(),
+            total_vesting_duration: end_date
+                .checked_sub(start_date)
+                .ok_or(ValhallaError::MathOverflow)?,
+            created_timestamp: now,
+            start_date,
+            last_payment_timestamp: start_date,
+            initial_deposit_amount: received_amount,
+            total_number_of_payouts: tranches.len() as u64,
+            payout_interval: 0,
+            number_of_payments_made: 0,
+            cliff_date: 0,
+            cliff_amount: 0,
+            is_cliff_payment_disbursed: false,
+            total_withdrawn: 0,
+            vesting_mode: VestingMode::Scheduled,
+            cancel_authority,
+            transfer_authority,
+            autopay,
+            allow_public_deposits,
+            is_split: false,
+            is_native: false,
+            token_account_bump: bumps.vault_ata,
+        });
+
+        self.schedule.set_inner(Schedule {
+            vault: self.vault.key(),
+            tranches,
+        });
+
+        // Transfer the amount to the vault token account
+        self.transfer(
+            deposit_amount,
+            self.creator_ata.to_account_info(),
+            self.vault_ata.to_account_info(),
+            self.creator.to_account_info(),
+            self.mint.to_account_info(),
+        )?;
+
+        // Transfer the token fee to the fee vault
+        let withheld_fee_amount = self.transfer(
+            token_fee_amount,
+            self.creator_ata.to_account_info(),
+            self.fee_vault.to_account_info(),
+            self.creator.to_account_info(),
+            self.mint.to_account_info(),
+        )?;
+
+        // Transfer sol fee to the fee stats account
+        let sol_fee_amount = dev_fee;
+        self.transfer_sol(sol_fee_amount, self.fee_stats.to_account_info())?;
+
+        self.fee_stats.mint = self.mint.key();
+        self.fee_stats.add_token_fees(
+            token_fee_amount
+                .checked_sub(withheld_fee_amount)
+                .ok_or(ValhallaError::MathOverflow)?,
+        )?;
+        self.fee_stats.add_sol_fees(sol_fee_amount)?;
+
+        // Fund the autopay escrow
+        let autopay_amount = self.fund_autopay_escrow()?;
+
+        emit!(VaultCreated {
+            vault: self.vault.key(),
+            creator: self.creator.key(),
+            recipient: self.recipient.key(),
+            mint: self.mint.key(),
+            deposit_amount: received_amount,
+            token_fee_amount,
+            sol_fee_amount,
+            autopay_amount,
+            timestamp: now,
+        });
+
+        // Mint governance tokens
+        match self.vault.cancel_authority {
+            Authority::Neither => self.mint_governance_tokens(bumps),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_tranches(&self, tranches: &[Tranche], current_time: u64) -> Result<()> {
+        require!(
+            !tranches.is_empty() && tranches.len() as u64 <= constants::MAX_TRANCHES,
+            ValhallaError::InvalidSchedule
+        );
+
+        // Tranches must have an amount and be in strictly increasing order.
+        require!(
+            tranches.iter().all(|tranche| tranche.amount > 0),
+            ValhallaError::InvalidSchedule
+        );
+        require!(
+            tranches
+                .windows(2)
+                .all(|pair| pair[0].timestamp < pair[1].timestamp),
+            ValhallaError::InvalidSchedule
+        );
+
+        // The schedule cannot start in the past.
+        require!(
+            tranches[0]
+                .timestamp
+                .saturating_add(constants::START_DATE_GRACE_PERIOD)
+                >= current_time,
+            ValhallaError::InvalidStartDate
+        );
+
+        Ok(())
+    }
+
+    /// Takes `amount` off the tranches, starting from the last one, and drops any tranche that is
+    /// left empty.
+    fn deduct_from_tranches(mut tranches: Vec<Tranche>, amount: u64) -> Result<Vec<Tranche>> {
+        let mut remaining_amount = amount;
+        for tranche in tranches.iter_mut().rev() {
+            let deducted_amount = tranche.amount.min(remaining_amount);
+            tranche.amount -= deducted_amount;
+            remaining_amount -= deducted_amount;
+        }
+        tranches.retain(|tranche| tranche.amount > 0);
+
+        require!(!tranches.is_empty(), ValhallaError::InvalidSchedule);
+
+        Ok(tranches)
+    }
+
+    fn fund_autopay_escrow(&mut self) -> Result<u64> {
+        let lamports_per_disburse = self
+            .config
+            .get_autopay_lamports_per_disburse(self.creator_ata.get_lamports())?;
+        let autopay_amount = lamports_per_disburse
+            .checked_mul(self.vault.total_number_of_payouts)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        let vault = self.vault.key();
+        let autopay_escrow = match &mut self.autopay_escrow {
+            Some(autopay_escrow) => autopay_escrow,
+            None => return Ok(0),
+        };
+        autopay_escrow.vault = vault;
+        autopay_escrow.lamports_per_disburse = lamports_per_disburse;
+        let to = autopay_escrow.to_account_info();
+
+        self.transfer_sol(autopay_amount, to)?;
+
+        Ok(autopay_amount)
+    }
+
+    fn transfer_sol(&self, amount: u64, to: AccountInfo<'info>) -> Result<()> {
+        let from = self.creator.to_account_info();
+        let transfer_ix = system_instruction::transfer(from.key, to.key, amount);
+
+        solana_program::program::invoke(
+            &transfer_ix,
+            &[from, to, self.system_program.to_account_info()],
+        )?;
+
+        Ok(())
+    }
+
+    fn transfer(
+        &self,
+        amount: u64,
+        from: AccountInfo<'info>,
+        to: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
+    ) -> Result<u64> {
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from,
+            to,
+            authority,
+            mint,
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        utils::transfer_checked_with_fee(cpi_ctx, amount, self.mint.decimals)
+    }
+
+    fn mint_governance_tokens(&self, bumps: &CreateVaultWithScheduleBumps) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            constants::GOVERNANCE_TOKEN_MINT_SEED,
+            &[bumps.governance_token_mint],
+        ]];
+
+        let cpi_program = self.governance_token_program.to_account_info();
+        let cpi_accounts = MintTo {
+            mint: self.governance_token_mint.to_account_info(),
+            to: self.creator_governance_ata.to_account_info(),
+            authority: self.governance_token_mint.to_account_info(),
+        };
+        let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        mint_to(cpi_context, self.config.governance_token_amount)
+    }
+}
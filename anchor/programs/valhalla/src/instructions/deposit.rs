@@ -0,0 +1,274 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use solana_program::system_instruction;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::VaultDeposited,
+    state::{AutopayEscrow, Config, FeeStats, MintFeeOverride, Vault},
+    utils, DepositMode, VestingMode,
+};
+
+#[derive(Accounts)]
+pub struct DepositVault<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub creator: SystemAccount<'info>,
+
+    #[account(seeds = [constants::CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [
+            vault.identifier.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            mint.key().as_ref(),
+            constants::VAULT_SEED
+        ],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [
+            vault.key().as_ref(),
+            constants::VAULT_ATA_SEED
+        ],
+        bump = vault.token_account_bump,
+        token::mint = mint,
+        token::authority = vault_ata,
+        token::token_program = token_program,
+    )]
+    pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = depositor,
+        associated_token::token_program = token_program,
+    )]
+    pub depositor_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        token::mint = config.governance_token_mint_key,
+        token::authority = depositor,
+    )]
+    pub depositor_governance_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        seeds = [mint.key().as_ref(), constants::FEE_VAULT_SEED],
+        bump,
+        token::mint = mint,
+        token::authority = fee_vault,
+        token::token_program = token_program,
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        seeds = [mint.key().as_ref(), constants::FEE_STATS_SEED],
+        bump,
+        space = FeeStats::INIT_SPACE,
+    )]
+    pub fee_stats: Box<Account<'info, FeeStats>>,
+
+    /// CHECK: may not exist, so it is loaded with `MintFeeOverride::try_load`
+    #[account(seeds = [mint.key().as_ref(), constants::MINT_FEE_OVERRIDE_SEED], bump)]
+    pub mint_fee_override: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        bump,
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DepositVault<'info> {
+    pub fn deposit(
+        &mut self,
+        amount_to_be_deposited: u64,
+        deposit_mode: DepositMode,
+    ) -> Result<()> {
+        require!(
+            !self.config.is_paused(constants::PAUSE_DEPOSIT),
+            ValhallaError::Paused
+        );
+        require!(
+            self.vault.allow_public_deposits || self.depositor.key() == self.vault.creator,
+            ValhallaError::Unauthorized
+        );
+
+        // Only interval vaults have payouts that can be extended or raised.
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        require!(
+            self.vault.vesting_mode == VestingMode::Interval,
+            ValhallaError::InvalidDeposit
+        );
+        require!(
+            !self.vault.is_expired(current_time)?
+                && self.vault.number_of_payments_made < self.vault.total_number_of_payouts,
+            ValhallaError::InvalidDeposit
+        );
+
+        // Extending an autopay vault adds payouts that its escrow has to pay for.
+        require!(
+            self.vault.autopay == self.autopay_escrow.is_some(),
+            ValhallaError::InvalidAutopayEscrow
+        );
+
+        let mut deposit_amount = (10u64)
+            .checked_pow(self.mint.decimals as u32)
+            .and_then(|multiplier| amount_to_be_deposited.checked_mul(multiplier))
+            .ok_or(ValhallaError::MathOverflow)?;
+        require!(deposit_amount > 0, ValhallaError::InvalidDeposit);
+
+        // Negotiated fees for the mint take precedence over the fees in the config.
+        let (token_fee_basis_points, dev_fee) = self.config.get_mint_fees(
+            MintFeeOverride::try_load(&self.mint_fee_override.to_account_info())?.as_ref(),
+        );
+
+        // Governance token holders get a discount on top of that. Deposits only pay the token fee.
+        let (token_fee_basis_points, _) = self.config.get_discounted_fees(
+            token_fee_basis_points,
+            dev_fee,
+            self.depositor_governance_ata
+                .as_ref()
+                .map_or(0, |depositor_governance_ata| {
+                    depositor_governance_ata.amount
+                }),
+        )?;
+
+        let token_fee_amount = utils::get_token_fee_amount(deposit_amount, token_fee_basis_points)?;
+
+        deposit_amount = deposit_amount
+            .checked_sub(token_fee_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        // Token-2022 transfer fees are withheld from what the vault token account receives.
+        let received_amount = deposit_amount
+            .checked_sub(utils::get_transfer_fee(
+                &self.mint.to_account_info(),
+                deposit_amount,
+            )?)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        let autopay_amount = match deposit_mode {
+            // Keep the amount per payout and add as many whole payouts as the deposit covers. The
+            // remainder is spread over the remaining payouts as with `IncreasePayouts`, so a
+            // deposit smaller than one payout cannot push the end date back.
+            DepositMode::ExtendSchedule => {
+                let amount_per_payout = self.vault.get_amount_per_payout()?.max(1);
+                let additional_payouts = received_amount
+                    .checked_div(amount_per_payout)
+                    .ok_or(ValhallaError::MathOverflow)?;
+
+                self.vault.total_number_of_payouts = self
+                    .vault
+                    .total_number_of_payouts
+                    .checked_add(additional_payouts)
+                    .ok_or(ValhallaError::MathOverflow)?;
+                self.vault.total_vesting_duration = self
+                    .vault
+                    .total_vesting_duration
+                    .checked_add(
+                        additional_payouts
+                            .checked_mul(self.vault.payout_interval)
+                            .ok_or(ValhallaError::MathOverflow)?,
+                    )
+                    .ok_or(ValhallaError::MathOverflow)?;
+
+                self.fund_autopay_escrow(additional_payouts)?
+            }
+            // Keep the end date; the deposit is spread over the remaining payouts.
+            DepositMode::IncreasePayouts => 0,
+        };
+
+        self.vault.initial_deposit_amount = self
+            .vault
+            .initial_deposit_amount
+            .checked_add(received_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        // Transfer the amount to the vault token account
+        self.transfer(deposit_amount, self.vault_ata.to_account_info())?;
+
+        // Transfer the token fee to the fee vault
+        let withheld_fee_amount =
+            self.transfer(token_fee_amount, self.fee_vault.to_account_info())?;
+
+        self.fee_stats.mint = self.mint.key();
+        self.fee_stats.add_token_fees(
+            token_fee_amount
+                .checked_sub(withheld_fee_amount)
+                .ok_or(ValhallaError::MathOverflow)?,
+        )?;
+
+        emit!(VaultDeposited {
+            vault: self.vault.key(),
+            depositor: self.depositor.key(),
+            deposit_amount: received_amount,
+            token_fee_amount,
+            autopay_amount,
+            total_number_of_payouts: self.vault.total_number_of_payouts,
+            timestamp: current_time,
+        });
+
+        Ok(())
+    }
+
+    /// Tops up the autopay escrow for `additional_payouts` more disburses, returning the lamports
+    /// paid by the depositor.
+    fn fund_autopay_escrow(&self, additional_payouts: u64) -> Result<u64> {
+        let autopay_escrow = match &self.autopay_escrow {
+            Some(autopay_escrow) => autopay_escrow,
+            None => return Ok(0),
+        };
+        let autopay_amount = autopay_escrow
+            .lamports_per_disburse
+            .checked_mul(additional_payouts)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        let from = self.depositor.to_account_info();
+        let to = autopay_escrow.to_account_info();
+        let transfer_ix = system_instruction::transfer(from.key, to.key, autopay_amount);
+
+        solana_program::program::invoke(
+            &transfer_ix,
+            &[from, to, self.system_program.to_account_info()],
+        )?;
+
+        Ok(autopay_amount)
+    }
+
+    fn transfer(&self, amount: u64, to: AccountInfo<'info>) -> Result<u64> {
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = TransferChecked {
+            from: self.depositor_ata.to_account_info(),
+            to,
+            authority: self.depositor.to_account_info(),
+            mint: self.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+
+        utils::transfer_checked_with_fee(cpi_ctx, amount, self.mint.decimals)
+    }
+}
@@ -1,12 +1,16 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_interface::{
-        mint_to, transfer_checked, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
-    },
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::{constants, errors::ValhallaError, state::Vault, Config};
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::VaultDisbursed,
+    state::{AutopayEscrow, Beneficiaries, Schedule, Vault},
+    utils, Config, VestingMode,
+};
 
 #[derive(Accounts)]
 pub struct DisburseVault<'info> {
@@ -15,6 +19,7 @@ pub struct DisburseVault<'info> {
 
     pub creator: SystemAccount<'info>,
 
+    #[account(constraint = vault.recipient == recipient.key())]
     pub recipient: SystemAccount<'info>,
 
     #[account(mut)]
@@ -48,6 +53,31 @@ pub struct DisburseVault<'info> {
     )]
     pub vault_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    #[account(
+        seeds = [
+            vault.key().as_ref(),
+            constants::SCHEDULE_SEED
+        ],
+        bump,
+    )]
+    pub schedule: Option<Box<Account<'info, Schedule>>>,
+
+    #[account(
+        seeds = [
+            vault.key().as_ref(),
+            constants::BENEFICIARIES_SEED
+        ],
+        bump,
+    )]
+    pub beneficiaries: Option<Box<Account<'info, Beneficiaries>>>,
+
+    #[account(
+        mut,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        bump,
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
+
     #[account(
         init_if_needed,
         payer = signer,
@@ -97,35 +127,210 @@ pub struct DisburseVault<'info> {
 }
 
 impl<'info> DisburseVault<'info> {
-    pub fn disburse(&mut self, bumps: &DisburseVaultBumps) -> Result<()> {
+    pub fn disburse(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        bumps: &DisburseVaultBumps,
+    ) -> Result<()> {
+        // Recipients and beneficiaries can always withdraw what has vested, even while disbursing
+        // is paused.
+        require!(
+            !self.config.is_paused(constants::PAUSE_DISBURSE)
+                || self.signer.key() == self.vault.recipient
+                || self.beneficiaries.as_ref().is_some_and(|beneficiaries| {
+                    beneficiaries.is_beneficiary(&self.signer.key())
+                }),
+            ValhallaError::Paused
+        );
+
         let current_time = Clock::get()?.unix_timestamp as u64;
         require!(!self.vault.is_locked(current_time)?, ValhallaError::Locked);
         require!(self.vault_ata.amount > 0, ValhallaError::NoPayout);
 
-        let transfer_amount = self.get_transfer_amount(current_time, self.vault_ata.amount)?;
-        self.transfer(transfer_amount)?;
+        let payouts_due = self.vault.get_number_of_payouts_due(current_time)?;
+        let transfer_amount = self.vault.get_claimable_amount(
+            current_time,
+            self.vault_ata.amount,
+            self.schedule.as_deref().map(|schedule| &**schedule),
+        )?;
+        require!(transfer_amount > 0, ValhallaError::NoPayout);
 
-        self.vault.last_payment_timestamp = current_time;
+        match self.vault.is_split {
+            true => self.transfer_to_beneficiaries(transfer_amount, remaining_accounts)?,
+            false => self.transfer(transfer_amount, self.recipient_ata.to_account_info())?,
+        }
+
+        self.vault.last_payment_timestamp = match self.vault.vesting_mode {
+            VestingMode::Continuous | VestingMode::Scheduled => current_time,
+            // Advance by whole intervals so the schedule never drifts.
+            VestingMode::Interval => self
+                .vault
+                .last_payment_timestamp
+                .checked_add(
+                    payouts_due
+                        .checked_mul(self.vault.payout_interval)
+                        .ok_or(ValhallaError::MathOverflow)?,
+                )
+                .ok_or(ValhallaError::MathOverflow)?,
+        };
+        self.vault.is_cliff_payment_disbursed = true;
+        self.vault.total_withdrawn = self
+            .vault
+            .total_withdrawn
+            .checked_add(transfer_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
         self.vault.number_of_payments_made = match transfer_amount == self.vault_ata.amount {
             true => self.vault.total_number_of_payouts,
-            false => self.vault.number_of_payments_made.checked_add(1).unwrap(),
+            false => match self.vault.vesting_mode {
+                // Continuous vaults count the payout intervals that have elapsed, however often
+                // they are disbursed.
+                VestingMode::Continuous => {
+                    Some(self.vault.get_number_of_payouts_completed(current_time)?)
+                }
+                VestingMode::Scheduled => Some(
+                    self.get_schedule()?
+                        .get_number_of_tranches_unlocked(current_time),
+                ),
+                VestingMode::Interval => {
+                    self.vault.number_of_payments_made.checked_add(payouts_due)
+                }
+            }
+            .ok_or(ValhallaError::MathOverflow)?,
         };
 
-        // Mint governance tokens to the creator
-        self.mint_governance_tokens(bumps)
+        // Pay whoever disbursed the vault out of the autopay escrow
+        let (autopay_fee_amount, number_of_payouts_paid) = self.pay_autopay_fee(current_time)?;
+
+        // Autopay vaults reward whoever disbursed them for each payout paid out of the escrow,
+        // since their governance tokens go to the creator.
+        let crank_reward_governance_token_amount = match self.vault.autopay {
+            true => self
+                .config
+                .crank_reward_governance_token_amount
+                .checked_mul(number_of_payouts_paid)
+                .ok_or(ValhallaError::MathOverflow)?,
+            false => 0,
+        };
+
+        emit!(VaultDisbursed {
+            vault: self.vault.key(),
+            recipient: self.recipient.key(),
+            signer: self.signer.key(),
+            amount: transfer_amount,
+            total_withdrawn: self.vault.total_withdrawn,
+            number_of_payments_made: self.vault.number_of_payments_made,
+            governance_token_amount: self.config.governance_token_amount,
+            autopay_fee_amount,
+            crank_reward_governance_token_amount,
+            timestamp: current_time,
+        });
+
+        // Mint governance tokens
+        match self.vault.autopay {
+            true => {
+                self.mint_governance_tokens(
+                    self.creator_governance_ata.to_account_info(),
+                    self.config.governance_token_amount,
+                    bumps,
+                )?;
+                self.mint_governance_tokens(
+                    self.signer_governance_ata.to_account_info(),
+                    crank_reward_governance_token_amount,
+                    bumps,
+                )
+            }
+            false => self.mint_governance_tokens(
+                self.signer_governance_ata.to_account_info(),
+                self.config.governance_token_amount,
+                bumps,
+            ),
+        }
     }
 
-    fn get_transfer_amount(&self, current_time: u64, vault_balance: u64) -> Result<u64> {
-        let amount_per_payout = self.vault.get_amount_per_payout()?;
-        let amount = amount_per_payout.min(vault_balance);
+    /// Pays the signer out of the autopay escrow for the payouts completed since the escrow last
+    /// paid out, returning the lamports paid and the number of payouts they cover.
+    fn pay_autopay_fee(&mut self, current_time: u64) -> Result<(u64, u64)> {
+        let number_of_payouts_completed =
+            self.vault.get_number_of_payouts_completed(current_time)?;
+        let autopay_escrow = match &mut self.autopay_escrow {
+            Some(autopay_escrow) => autopay_escrow,
+            None => return Ok((0, 0)),
+        };
+
+        // Only payouts completed since the last disburse are paid for, so repeated disburses
+        // cannot drain the escrow.
+        let escrow_balance = utils::get_native_balance(&autopay_escrow.to_account_info())?;
+        let autopay_fee_amount =
+            autopay_escrow.get_autopay_fee(number_of_payouts_completed, escrow_balance);
+        let number_of_payouts_paid =
+            number_of_payouts_completed.saturating_sub(autopay_escrow.number_of_payouts_paid);
+        autopay_escrow.number_of_payouts_paid = autopay_escrow
+            .number_of_payouts_paid
+            .max(number_of_payouts_completed);
+        if autopay_fee_amount > 0 {
+            autopay_escrow.sub_lamports(autopay_fee_amount)?;
+            self.signer.add_lamports(autopay_fee_amount)?;
+        }
+
+        Ok((autopay_fee_amount, number_of_payouts_paid))
+    }
 
-        match self.vault.is_expired(current_time)? {
-            true => Ok(vault_balance),
-            false => Ok(amount),
+    fn get_schedule(&self) -> Result<&Schedule> {
+        match &self.schedule {
+            Some(schedule) => Ok(schedule),
+            None => Err(ValhallaError::InvalidSchedule.into()),
         }
     }
 
-    fn transfer(&self, amount: u64) -> Result<()> {
+    fn transfer_to_beneficiaries(
+        &self,
+        amount: u64,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let beneficiaries = match &self.beneficiaries {
+            Some(beneficiaries) => beneficiaries,
+            None => return Err(ValhallaError::InvalidBeneficiaries.into()),
+        };
+        require!(
+            remaining_accounts.len() == beneficiaries.beneficiaries.len(),
+            ValhallaError::InvalidBeneficiaries
+        );
+
+        let shares = beneficiaries.get_shares(amount)?;
+        for ((beneficiary, beneficiary_ata), share) in beneficiaries
+            .beneficiaries
+            .iter()
+            .zip(remaining_accounts)
+            .zip(shares)
+        {
+            // Each beneficiary token account must hold the vault mint and belong to the beneficiary.
+            require_keys_eq!(
+                *beneficiary_ata.owner,
+                self.token_program.key(),
+                ValhallaError::InvalidBeneficiaries
+            );
+            let token_account =
+                TokenAccount::try_deserialize(&mut &beneficiary_ata.data.borrow()[..])?;
+            require_keys_eq!(
+                token_account.owner,
+                beneficiary.recipient,
+                ValhallaError::InvalidBeneficiaries
+            );
+            require_keys_eq!(
+                token_account.mint,
+                self.mint.key(),
+                ValhallaError::InvalidBeneficiaries
+            );
+
+            if share > 0 {
+                self.transfer(share, beneficiary_ata.clone())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn transfer(&self, amount: u64, to: AccountInfo<'info>) -> Result<()> {
         let lock_key = self.vault.key();
         let signer_seeds: &[&[&[u8]]] = &[&[
             lock_key.as_ref(),
@@ -137,28 +342,31 @@ impl<'info> DisburseVault<'info> {
         let cpi_accounts = TransferChecked {
             from: self.vault_ata.to_account_info(),
             mint: self.mint.to_account_info(),
-            to: self.recipient_ata.to_account_info(),
+            to,
             authority: self.vault_ata.to_account_info(),
         };
         let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
 
-        transfer_checked(cpi_ctx, amount, self.mint.decimals)?;
+        utils::transfer_checked_with_fee(cpi_ctx, amount, self.mint.decimals)?;
 
         Ok(())
     }
 
-    fn mint_governance_tokens(&self, bumps: &DisburseVaultBumps) -> Result<()> {
+    fn mint_governance_tokens(
+        &self,
+        to: AccountInfo<'info>,
+        amount: u64,
+        bumps: &DisburseVaultBumps,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
         let signer_seeds: &[&[&[u8]]] = &[&[
             constants::GOVERNANCE_TOKEN_MINT_SEED,
             &[bumps.governance_token_mint],
         ]];
 
-        let to = if self.vault.autopay {
-            self.creator_governance_ata.to_account_info()
-        } else {
-            self.signer_governance_ata.to_account_info()
-        };
-
         let cpi_program = self.governance_token_program.to_account_info();
         let cpi_accounts = MintTo {
             to,
@@ -167,6 +375,6 @@ impl<'info> DisburseVault<'info> {
         };
         let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
 
-        mint_to(cpi_context, self.config.governance_token_amount)
+        mint_to(cpi_context, amount)
     }
 }
@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::spl_token::native_mint,
+    token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    events::VaultDisbursed,
+    state::{AutopayEscrow, Config, Vault},
+    utils, VestingMode,
+};
+
+#[derive(Accounts)]
+pub struct DisburseSolVault<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    pub creator: SystemAccount<'info>,
+
+    #[account(mut, constraint = vault.recipient == recipient.key())]
+    pub recipient: SystemAccount<'info>,
+
+    #[account(seeds = [constants::CONFIG_SEED], bump)]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        constraint = vault.is_native @ ValhallaError::InvalidVaultType,
+        seeds = [
+            vault.identifier.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            native_mint::ID.as_ref(),
+            constants::VAULT_SEED
+        ],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        mut,
+        seeds = [vault.key().as_ref(), constants::AUTOPAY_ESCROW_SEED],
+        bump,
+    )]
+    pub autopay_escrow: Option<Box<Account<'info, AutopayEscrow>>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = governance_token_mint,
+        associated_token::authority = signer,
+        associated_token::token_program = governance_token_program,
+    )]
+    pub signer_governance_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = governance_token_mint,
+        associated_token::authority = creator,
+        associated_token::token_program = governance_token_program,
+    )]
+    pub creator_governance_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        mint::decimals = 9,
+        mint::authority = governance_token_mint,
+        mint::token_program = governance_token_program,
+        seeds = [constants::GOVERNANCE_TOKEN_MINT_SEED],
+        bump,
+    )]
+    pub governance_token_mint: InterfaceAccount<'info, Mint>,
+
+    pub governance_token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DisburseSolVault<'info> {
+    pub fn disburse(&mut self, bumps: &DisburseSolVaultBumps) -> Result<()> {
+        // Recipients can always withdraw what has vested, even while disbursing is paused.
+        require!(
+            !self.config.is_paused(constants::PAUSE_DISBURSE)
+                || self.signer.key() == self.vault.recipient,
+            ValhallaError::Paused
+        );
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let vault_balance = utils::get_native_balance(&self.vault.to_account_info())?;
+        require!(!self.vault.is_locked(current_time)?, ValhallaError::Locked);
+        require!(vault_balance > 0, ValhallaError::NoPayout);
+
+        let payouts_due = self.vault.get_number_of_payouts_due(current_time)?;
+        let transfer_amount = self
+            .vault
+            .get_claimable_amount(current_time, vault_balance, None)?;
+        require!(transfer_amount > 0, ValhallaError::NoPayout);
+
+        // The vault is owned by the program, so lamports can be moved without a CPI.
+        self.vault.sub_lamports(transfer_amount)?;
+        self.recipient.add_lamports(transfer_amount)?;
+
+        self.vault.last_payment_timestamp = match self.vault.vesting_mode {
+            VestingMode::Continuous | VestingMode::Scheduled => current_time,
+            // Advance by whole intervals so the schedule never drifts.
+            VestingMode::Interval => self
+                .vault
+                .last_payment_timestamp
+                .checked_add(
+                    payouts_due
+                        .checked_mul(self.vault.payout_interval)
+                        .ok_or(ValhallaError::MathOverflow)?,
+                )
+                .ok_or(ValhallaError::MathOverflow)?,
+        };
+        self.vault.is_cliff_payment_disbursed = true;
+        self.vault.total_withdrawn = self
+            .vault
+            .total_withdrawn
+            .checked_add(transfer_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+        self.vault.number_of_payments_made = match transfer_amount == vault_balance {
+            true => self.vault.total_number_of_payouts,
+            false => match self.vault.vesting_mode {
+                VestingMode::Interval => {
+                    self.vault.number_of_payments_made.checked_add(payouts_due)
+                }
+                // Continuous vaults count the payout intervals that have elapsed, however often
+                // they are disbursed.
+                _ => Some(self.vault.get_number_of_payouts_completed(current_time)?),
+            }
+            .ok_or(ValhallaError::MathOverflow)?,
+        };
+
+        // Pay whoever disbursed the vault out of the autopay escrow
+        let (autopay_fee_amount, number_of_payouts_paid) = self.pay_autopay_fee(current_time)?;
+
+        // Autopay vaults reward whoever disbursed them for each payout paid out of the escrow,
+        // since their governance tokens go to the creator.
+        let crank_reward_governance_token_amount = match self.vault.autopay {
+            true => self
+                .config
+                .crank_reward_governance_token_amount
+                .checked_mul(number_of_payouts_paid)
+                .ok_or(ValhallaError::MathOverflow)?,
+            false => 0,
+        };
+
+        emit!(VaultDisbursed {
+            vault: self.vault.key(),
+            recipient: self.recipient.key(),
+            signer: self.signer.key(),
+            amount: transfer_amount,
+            total_withdrawn: self.vault.total_withdrawn,
+            number_of_payments_made: self.vault.number_of_payments_made,
+            governance_token_amount: self.config.governance_token_amount,
+            autopay_fee_amount,
+            crank_reward_governance_token_amount,
+            timestamp: current_time,
+        });
+
+        // Mint governance tokens
+        match self.vault.autopay {
+            true => {
+                self.mint_governance_tokens(
+                    self.creator_governance_ata.to_account_info(),
+                    self.config.governance_token_amount,
+                    bumps,
+                )?;
+                self.mint_governance_tokens(
+                    self.signer_governance_ata.to_account_info(),
+                    crank_reward_governance_token_amount,
+                    bumps,
+                )
+            }
+            false => self.mint_governance_tokens(
+                self.signer_governance_ata.to_account_info(),
+                self.config.governance_token_amount,
+                bumps,
+            ),
+        }
+    }
+
+    /// Pays the signer out of the autopay escrow for the payouts completed since the escrow last
+    /// paid out, returning the lamports paid and the number of payouts they cover.
+    fn pay_autopay_fee(&mut self, current_time: u64) -> Result<(u64, u64)> {
+        let number_of_payouts_completed =
+            self.vault.get_number_of_payouts_completed(current_time)?;
+        let autopay_escrow = match &mut self.autopay_escrow {
+            Some(autopay_escrow) => autopay_escrow,
+            None => return Ok((0, 0)),
+        };
+
+        // Only payouts completed since the last disburse are paid for, so repeated disburses
+        // cannot drain the escrow.
+        let escrow_balance = utils::get_native_balance(&autopay_escrow.to_account_info())?;
+        let autopay_fee_amount =
+            autopay_escrow.get_autopay_fee(number_of_payouts_completed, escrow_balance);
+        let number_of_payouts_paid =
+            number_of_payouts_completed.saturating_sub(autopay_escrow.number_of_payouts_paid);
+        autopay_escrow.number_of_payouts_paid = autopay_escrow
+            .number_of_payouts_paid
+            .max(number_of_payouts_completed);
+        if autopay_fee_amount > 0 {
+            autopay_escrow.sub_lamports(autopay_fee_amount)?;
+            self.signer.add_lamports(autopay_fee_amount)?;
+        }
+
+        Ok((autopay_fee_amount, number_of_payouts_paid))
+    }
+
+    fn mint_governance_tokens(
+        &self,
+        to: AccountInfo<'info>,
+        amount: u64,
+        bumps: &DisburseSolVaultBumps,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            constants::GOVERNANCE_TOKEN_MINT_SEED,
+            &[bumps.governance_token_mint],
+        ]];
+
+        let cpi_program = self.governance_token_program.to_account_info();
+        let cpi_accounts = MintTo {
+            to,
+            mint: self.governance_token_mint.to_account_info(),
+            authority: self.governance_token_mint.to_account_info(),
+        };
+        let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer_seeds);
+
+        mint_to(cpi_context, amount)
+    }
+}
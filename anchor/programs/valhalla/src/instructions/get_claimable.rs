@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    constants,
+    errors::ValhallaError,
+    state::{Schedule, Vault},
+    utils, Claimable,
+};
+
+#[derive(Accounts)]
+pub struct GetClaimable<'info> {
+    #[account(
+        seeds = [
+            vault.identifier.to_le_bytes().as_ref(),
+            vault.creator.as_ref(),
+            vault.mint.as_ref(),
+            constants::VAULT_SEED
+        ],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    #[account(
+        seeds = [
+            vault.key().as_ref(),
+            constants::VAULT_ATA_SEED
+        ],
+        bump = vault.token_account_bump,
+    )]
+    pub vault_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        seeds = [
+            vault.key().as_ref(),
+            constants::SCHEDULE_SEED
+        ],
+        bump,
+    )]
+    pub schedule: Option<Box<Account<'info, Schedule>>>,
+}
+
+impl<'info> GetClaimable<'info> {
+    pub fn get_claimable(&self) -> Result<Claimable> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let schedule = self.schedule.as_deref().map(|schedule| &**schedule);
+        let vault_balance = self.get_vault_balance()?;
+
+        let amount = match self.vault.is_locked(current_time)? {
+            true => 0,
+            false => self
+                .vault
+                .get_claimable_amount(current_time, vault_balance, schedule)?,
+        };
+
+        Ok(Claimable {
+            amount,
+            next_unlock_time: self.vault.get_next_unlock_time(current_time, schedule)?,
+            remaining_balance: vault_balance,
+        })
+    }
+
+    /// Native vaults hold their lamports in the vault account, while every other vault must pass
+    /// its token account.
+    fn get_vault_balance(&self) -> Result<u64> {
+        match (self.vault.is_native, &self.vault_ata) {
+            (true, _) => utils::get_native_balance(&self.vault.to_account_info()),
+            (false, Some(vault_ata)) => Ok(vault_ata.amount),
+            (false, None) => Err(ValhallaError::InvalidVaultType.into()),
+        }
+    }
+}
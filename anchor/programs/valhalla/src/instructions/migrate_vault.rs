@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use solana_program::system_instruction;
+
+use crate::{
+    constants, errors::ValhallaError, events::VaultMigrated, Authority, Vault, VestingMode,
+};
+
+#[derive(Accounts)]
+#[instruction(identifier: u64)]
+pub struct MigrateVault<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub creator: SystemAccount<'info>,
+
+    /// CHECK: a vault with the legacy layout cannot be loaded as a `Vault`, so it is checked and
+    /// deserialized in the instruction.
+    #[account(
+        mut,
+        seeds = [
+            identifier.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            mint.key().as_ref(),
+            constants::VAULT_SEED
+        ],
+        bump,
+        owner = crate::ID,
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            vault.key().as_ref(),
+            constants::VAULT_ATA_SEED
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = vault_ata,
+    )]
+    pub vault_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateVault<'info> {
+    pub fn migrate(&mut self) -> Result<()> {
+        let vault = self.vault.to_account_info();
+        require!(
+            vault.data_len() == Vault::LEGACY_SPACE,
+            ValhallaError::AlreadyMigrated
+        );
+
+        // Whoever migrates the vault pays for the extra rent.
+        let rent = Rent::get()?.minimum_balance(Vault::INIT_SPACE);
+        let shortfall = rent.saturating_sub(vault.lamports());
+        if shortfall > 0 {
+            let from = self.payer.to_account_info();
+            let transfer_ix = system_instruction::transfer(from.key, vault.key, shortfall);
+
+            solana_program::program::invoke(
+                &transfer_ix,
+                &[from, vault.clone(), self.system_program.to_account_info()],
+            )?;
+        }
+
+        // The new fields are appended to the legacy layout, so the zeroed tail deserializes as
+        // an interval vault without a cliff that cannot be transferred or split.
+        vault.realloc(Vault::INIT_SPACE, true)?;
+        let mut migrated_vault = Vault::try_deserialize(&mut &vault.try_borrow_data()?[..])?;
+        migrated_vault.vesting_mode = VestingMode::Interval;
+        migrated_vault.transfer_authority = Authority::Neither;
+        migrated_vault.total_withdrawn = migrated_vault
+            .initial_deposit_amount
+            .saturating_sub(self.vault_ata.amount);
+        migrated_vault.try_serialize(&mut &mut vault.try_borrow_mut_data()?[..])?;
+
+        emit!(VaultMigrated {
+            vault: vault.key(),
+            total_withdrawn: migrated_vault.total_withdrawn,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+}
@@ -1,11 +1,33 @@
 mod admin;
 mod cancel;
+mod cancel_sol;
 mod close;
+mod close_sol;
 mod create;
+mod create_batch;
+mod create_beneficiaries;
+mod create_sol;
+mod create_with_schedule;
+mod deposit;
 mod disburse;
+mod disburse_sol;
+mod get_claimable;
+mod migrate_vault;
+mod transfer_recipient;
 
 pub use admin::*;
 pub use cancel::*;
+pub use cancel_sol::*;
 pub use close::*;
+pub use close_sol::*;
 pub use create::*;
+pub use create_batch::*;
+pub use create_beneficiaries::*;
+pub use create_sol::*;
+pub use create_with_schedule::*;
+pub use deposit::*;
 pub use disburse::*;
+pub use disburse_sol::*;
+pub use get_claimable::*;
+pub use migrate_vault::*;
+pub use transfer_recipient::*;
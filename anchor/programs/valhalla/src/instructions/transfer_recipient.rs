@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants, errors::ValhallaError, events::VaultRecipientTransferred, state::Vault, Authority,
+};
+
+#[derive(Accounts)]
+pub struct TransferRecipient<'info> {
+    #[account(constraint = vault.creator == creator.key())]
+    pub creator: SystemAccount<'info>,
+
+    #[account(constraint = vault.recipient == recipient.key())]
+    pub recipient: SystemAccount<'info>,
+
+    pub new_recipient: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            vault.identifier.to_le_bytes().as_ref(),
+            creator.key().as_ref(),
+            vault.mint.as_ref(),
+            constants::VAULT_SEED
+        ],
+        bump,
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+}
+
+impl<'info> TransferRecipient<'info> {
+    pub fn transfer_recipient(&mut self) -> Result<()> {
+        self.validate_transfer_authority()?;
+
+        let previous_recipient = self.vault.recipient;
+        self.vault.recipient = self.new_recipient.key();
+
+        emit!(VaultRecipientTransferred {
+            vault: self.vault.key(),
+            previous_recipient,
+            new_recipient: self.vault.recipient,
+            timestamp: Clock::get()?.unix_timestamp as u64,
+        });
+
+        Ok(())
+    }
+
+    fn validate_transfer_authority(&self) -> Result<()> {
+        let creator_signed = self.creator.to_account_info().is_signer;
+        let recipient_signed = self.recipient.to_account_info().is_signer;
+
+        // The recipient must always sign so a grant cannot be taken away from them; the creator
+        // can only co-sign.
+        let authorized = match self.vault.transfer_authority {
+            Authority::Neither | Authority::Creator => false,
+            Authority::Recipient => recipient_signed,
+            Authority::Both => recipient_signed && creator_signed,
+        };
+
+        require!(authorized, ValhallaError::Unauthorized);
+
+        Ok(())
+    }
+}
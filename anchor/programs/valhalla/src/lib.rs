@@ -2,11 +2,14 @@ use anchor_lang::prelude::*;
 
 mod constants;
 mod errors;
+mod events;
 mod id;
 mod instructions;
 mod state;
 mod types;
+mod utils;
 
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 pub use types::*;
@@ -31,11 +34,12 @@ pub mod valhalla {
     /// * `uri` - The URI of the token.
     /// * `decimals` - The number of decimals for the token.
     /// * `dev_fee` - The fee value for the configuration.
-    /// * `autopay_multiplier` - The multiplier for the autopay fee.
+    /// * `autopay_multiplier` - The multiplier for the lamports set aside per autopay disburse.
     /// * `token_fee_basis_points` - The basis points of the token fee.
     /// * `governance_token_amount` - The amount of governance tokens to be minted on disburse.
     /// * `dev_treasury_governance_token_amount` - The amount of governance tokens to be minted for the dev treasury.
     /// * `dao_treasury_governance_token_amount` - The amount of governance tokens to be minted for the dao treasury.
+    /// * `config_change_delay` - The number of seconds a queued config change must wait before it can be executed.
     ///
     /// # Errors
     ///
@@ -50,6 +54,7 @@ pub mod valhalla {
         autopay_multiplier: u64,
         token_fee_basis_points: u64,
         governance_token_amount: u64,
+        config_change_delay: u64,
     ) -> Result<()> {
         ctx.accounts.create(
             name,
@@ -60,24 +65,27 @@ pub mod valhalla {
             autopay_multiplier,
             token_fee_basis_points,
             governance_token_amount,
+            config_change_delay,
             &ctx.bumps,
         )
     }
 
-    /// Updates the admin of the program.
+    /// Migrates a config created before the admin handover, config change delay, mint policy,
+    /// pause, fee discount and crank reward fields were added to the current layout.
     ///
     /// # Arguments
     ///
     /// * `ctx` - The context for the transaction.
+    /// * `config_change_delay` - The number of seconds a queued config change must wait before it can be executed.
     ///
     /// # Errors
     ///
-    /// Returns an error if the admin update fails.
-    pub fn update_admin(ctx: Context<UpdateAdmin>) -> Result<()> {
-        ctx.accounts.update()
+    /// Returns an error if the signer is not the admin or the config has already been migrated.
+    pub fn migrate_config(ctx: Context<MigrateConfig>, config_change_delay: u64) -> Result<()> {
+        ctx.accounts.migrate(config_change_delay)
     }
 
-    /// Updates the dao treasury.
+    /// Proposes a new admin of the program, who must accept before taking over.
     ///
     /// # Arguments
     ///
@@ -85,57 +93,189 @@ pub mod valhalla {
     ///
     /// # Errors
     ///
-    /// Returns an error if the dao treasury update fails.
-    pub fn update_dao_treasury(ctx: Context<UpdateDaoTreasury>) -> Result<()> {
-        ctx.accounts.update()
+    /// Returns an error if the signer is not the admin.
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+        ctx.accounts.propose()
     }
 
-    /// Updates the fee for the development team.
+    /// Accepts the admin role of the program on behalf of the proposed admin.
     ///
     /// # Arguments
     ///
     /// * `ctx` - The context for the transaction.
-    /// * `dev_fee` - The fee value for the configuration.
     ///
     /// # Errors
     ///
-    /// Returns an error if the dev fee update fails.
-    pub fn update_dev_fee(ctx: Context<UpdateDevFee>, dev_fee: u64) -> Result<()> {
-        ctx.accounts.update(dev_fee)
+    /// Returns an error if the signer is not the proposed admin.
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.accept()
     }
 
-    /// Updates the amount of governance tokens to be minted.
+    /// Pauses or unpauses the program. `paused` halts creating vaults, deposits and disbursing
+    /// on behalf of others, while `pause_flags` pauses them individually. Recipients can always
+    /// disburse their own vaults.
     ///
     /// # Arguments
     ///
     /// * `ctx` - The context for the transaction.
-    /// * `governance_token_amount` - The amount of governance tokens to be minted on disburse.
+    /// * `paused` - Whether the whole program is paused.
+    /// * `pause_flags` - A bitmask of `PAUSE_CREATE`, `PAUSE_DEPOSIT` and `PAUSE_DISBURSE`.
     ///
     /// # Errors
     ///
-    /// Returns an error if the governance token amount update fails.
-    pub fn update_governance_token_amount(
-        ctx: Context<UpdateGovernanceTokenAmount>,
-        governance_token_amount: u64,
+    /// Returns an error if the signer is not the admin.
+    pub fn update_pause(ctx: Context<UpdatePause>, paused: bool, pause_flags: u8) -> Result<()> {
+        ctx.accounts.update(paused, pause_flags)
+    }
+
+    /// Queues a change to the fees, governance token amount, dao treasury, config change delay or
+    /// crank rewards. The change can be executed once the config change delay has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `config_change` - The change to apply to the configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the change is invalid or another change is already queued.
+    pub fn queue_config_change(
+        ctx: Context<QueueConfigChange>,
+        config_change: ConfigChange,
     ) -> Result<()> {
-        ctx.accounts.update(governance_token_amount)
+        ctx.accounts.queue(config_change)
     }
 
-    /// Updates the basis points of the token fee.
+    /// Applies the queued config change.
     ///
     /// # Arguments
     ///
     /// * `ctx` - The context for the transaction.
-    /// * `token_fee_basis_points` - The basis points of the token fee.
     ///
     /// # Errors
     ///
-    /// Returns an error if the token fee basis points update fails.
-    pub fn update_token_fee_basis_points(
-        ctx: Context<UpdateTokenFeeBasisPoints>,
+    /// Returns an error if the config change delay has not passed yet.
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        ctx.accounts.execute()
+    }
+
+    /// Discards the queued config change.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer is not the admin.
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        ctx.accounts.cancel()
+    }
+
+    /// Updates which Token-2022 mint extensions vaults can be created with.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `mint_extension_policy` - Whether `mint_extensions` lists denied or allowed extensions.
+    /// * `mint_extensions` - A bitmask where bit `n` is the extension with discriminant `n`.
+    /// * `allow_freeze_authority` - Whether mints with a freeze authority are allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mint extension policy update fails.
+    pub fn update_mint_extension_policy(
+        ctx: Context<UpdateMintExtensionPolicy>,
+        mint_extension_policy: MintExtensionPolicy,
+        mint_extensions: u64,
+        allow_freeze_authority: bool,
+    ) -> Result<()> {
+        ctx.accounts.update(
+            mint_extension_policy,
+            mint_extensions,
+            allow_freeze_authority,
+        )
+    }
+
+    /// Updates the discounts on the token and dev fees given to creators holding governance tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `fee_discount_tiers` - The tiers, ordered by the governance token amount they require.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer is not the admin or the tiers are invalid.
+    pub fn update_fee_discount_tiers(
+        ctx: Context<UpdateFeeDiscountTiers>,
+        fee_discount_tiers: Vec<FeeDiscountTier>,
+    ) -> Result<()> {
+        ctx.accounts.update(fee_discount_tiers)
+    }
+
+    /// Sets the fees charged when creating vaults for a mint, overriding the fees in the config.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `token_fee_basis_points` - The token fee for the mint, in basis points.
+    /// * `dev_fee` - The SOL fee for the mint.
+    /// * `fee_waived` - Whether vaults for the mint are created without token or dev fees.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer is not the admin or the fees are invalid or higher than the
+    /// fees in the config.
+    pub fn set_mint_fee_override(
+        ctx: Context<SetMintFeeOverride>,
         token_fee_basis_points: u64,
+        dev_fee: u64,
+        fee_waived: bool,
     ) -> Result<()> {
-        ctx.accounts.update(token_fee_basis_points)
+        ctx.accounts
+            .set(token_fee_basis_points, dev_fee, fee_waived)
+    }
+
+    /// Removes the fee override of a mint, so vaults for it are charged the fees in the config.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer is not the admin.
+    pub fn close_mint_fee_override(ctx: Context<CloseMintFeeOverride>) -> Result<()> {
+        ctx.accounts.close()
+    }
+
+    /// Pays the fees collected for a mint out of the fee vault, sending token fees to the dao
+    /// treasury and SOL fees to the dev treasury.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer is not the admin or the transfer fails.
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>) -> Result<()> {
+        ctx.accounts.withdraw(&ctx.bumps)
+    }
+
+    /// Pays the fees collected from native vaults, sending token fees to the dao treasury and SOL
+    /// fees to the dev treasury.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer is not the admin.
+    pub fn withdraw_sol_fees(ctx: Context<WithdrawSolFees>) -> Result<()> {
+        ctx.accounts.withdraw()
     }
 
     /// Mints governance tokens to the receiver.
@@ -159,16 +299,23 @@ pub mod valhalla {
     /// * `ctx` - The context for the transaction.
     /// * `identifier` - The identifier of the vault.
     /// * `name` - The name of the vault.
-    /// * `amount_to_be_vested` - The amount to be vested in the vault.
+    /// * `amount_to_be_vested` - The amount to be vested in the vault, in whole tokens.
     /// * `total_vesting_duration` - The total duration of the vesting period.
     /// * `start_date` - The start date of the vesting period.
     /// * `payout_interval` - The interval at which the vested amount is disbursed.
+    /// * `cliff_date` - The date before which nothing can be disbursed.
+    /// * `cliff_amount` - The lump sum released on the first disbursement after the cliff date, in whole tokens.
+    /// * `vesting_mode` - Whether the vested amount is released per interval or continuously.
     /// * `cancel_authority` - The authority to cancel the vault.
-    /// * `autopay` - Whether the vault should automatically disburse the vested amount.
+    /// * `transfer_authority` - The authority that must sign to transfer the vault to a new recipient.
+    ///   The recipient always signs, so it cannot be `Creator`.
+    /// * `autopay` - Whether the vault should automatically disburse the vested amount, paid for
+    ///   from its autopay escrow.
+    /// * `allow_public_deposits` - Whether anyone, not just the creator, can add funds to the vault.
     ///
     /// # Errors
     ///
-    /// Returns an error if the vault creation fails.
+    /// Returns an error if the vault creation fails or the amounts overflow.
     pub fn create(
         ctx: Context<CreateVault>,
         identifier: u64,
@@ -177,8 +324,13 @@ pub mod valhalla {
         total_vesting_duration: u64,
         start_date: u64,
         payout_interval: u64,
+        cliff_date: u64,
+        cliff_amount: u64,
+        vesting_mode: VestingMode,
         cancel_authority: Authority,
+        transfer_authority: Authority,
         autopay: bool,
+        allow_public_deposits: bool,
     ) -> Result<()> {
         ctx.accounts.create(
             identifier,
@@ -187,14 +339,197 @@ pub mod valhalla {
             total_vesting_duration,
             start_date,
             payout_interval,
+            cliff_date,
+            cliff_amount,
+            vesting_mode,
+            cancel_authority,
+            transfer_authority,
+            autopay,
+            allow_public_deposits,
+            &ctx.bumps,
+        )
+    }
+
+    /// Creates a new vault with the amounts given in base units of the mint, so fractional
+    /// amounts can be vested.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `identifier` - The identifier of the vault.
+    /// * `name` - The name of the vault.
+    /// * `amount_to_be_vested` - The amount to be vested in the vault, in base units.
+    /// * `total_vesting_duration` - The total duration of the vesting period.
+    /// * `start_date` - The start date of the vesting period.
+    /// * `payout_interval` - The interval at which the vested amount is disbursed.
+    /// * `cliff_date` - The date before which nothing can be disbursed.
+    /// * `cliff_amount` - The lump sum released on the first disbursement after the cliff date, in base units.
+    /// * `vesting_mode` - Whether the vested amount is released per interval or continuously.
+    /// * `cancel_authority` - The authority to cancel the vault.
+    /// * `transfer_authority` - The authority that must sign to transfer the vault to a new recipient.
+    ///   The recipient always signs, so it cannot be `Creator`.
+    /// * `autopay` - Whether the vault should automatically disburse the vested amount, paid for
+    ///   from its autopay escrow.
+    /// * `allow_public_deposits` - Whether anyone, not just the creator, can add funds to the vault.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vault creation fails or the amounts overflow.
+    pub fn create_with_base_units(
+        ctx: Context<CreateVault>,
+        identifier: u64,
+        name: [u8; 32],
+        amount_to_be_vested: u64,
+        total_vesting_duration: u64,
+        start_date: u64,
+        payout_interval: u64,
+        cliff_date: u64,
+        cliff_amount: u64,
+        vesting_mode: VestingMode,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+        allow_public_deposits: bool,
+    ) -> Result<()> {
+        ctx.accounts.create_with_base_units(
+            identifier,
+            name,
+            amount_to_be_vested,
+            total_vesting_duration,
+            start_date,
+            payout_interval,
+            cliff_date,
+            cliff_amount,
+            vesting_mode,
+            cancel_authority,
+            transfer_authority,
+            autopay,
+            allow_public_deposits,
+            &ctx.bumps,
+        )
+    }
+
+    /// Creates a vault for each recipient in the batch, funded from the same creator token account.
+    ///
+    /// The remaining accounts must contain the recipient, vault and vault token account of each
+    /// vault, followed by its autopay escrow when `autopay` is set, in the same order as `vaults`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `name` - The name of the vaults.
+    /// * `vaults` - The identifier, amount to be vested and cliff amount of each vault.
+    /// * `total_vesting_duration` - The total duration of the vesting period.
+    /// * `start_date` - The start date of the vesting period.
+    /// * `payout_interval` - The interval at which the vested amount is disbursed.
+    /// * `cliff_date` - The date before which nothing can be disbursed.
+    /// * `vesting_mode` - Whether the vested amount is released per interval or continuously.
+    /// * `cancel_authority` - The authority to cancel the vaults.
+    /// * `transfer_authority` - The authority that must sign to transfer the vaults to a new recipient.
+    ///   The recipient always signs, so it cannot be `Creator`.
+    /// * `autopay` - Whether the vaults should automatically disburse the vested amount, paid for
+    ///   from their autopay escrows.
+    /// * `allow_public_deposits` - Whether anyone, not just the creator, can add funds to the vaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch creation fails.
+    pub fn create_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateBatchVault<'info>>,
+        name: [u8; 32],
+        vaults: Vec<BatchVault>,
+        total_vesting_duration: u64,
+        start_date: u64,
+        payout_interval: u64,
+        cliff_date: u64,
+        vesting_mode: VestingMode,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+        allow_public_deposits: bool,
+    ) -> Result<()> {
+        ctx.accounts.create(
+            ctx.remaining_accounts,
+            name,
+            vaults,
+            total_vesting_duration,
+            start_date,
+            payout_interval,
+            cliff_date,
+            vesting_mode,
+            cancel_authority,
+            transfer_authority,
+            autopay,
+            allow_public_deposits,
+            &ctx.bumps,
+        )
+    }
+
+    /// Creates a new vault that unlocks according to a custom schedule of tranches.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `identifier` - The identifier of the vault.
+    /// * `name` - The name of the vault.
+    /// * `tranches` - The timestamps and amounts unlocked by the vault, in increasing order.
+    /// * `cancel_authority` - The authority to cancel the vault.
+    /// * `transfer_authority` - The authority that must sign to transfer the vault to a new recipient.
+    ///   The recipient always signs, so it cannot be `Creator`.
+    /// * `autopay` - Whether the vault should automatically disburse the vested amount, paid for
+    ///   from its autopay escrow.
+    /// * `allow_public_deposits` - Whether anyone, not just the creator, can add funds to the vault.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vault creation fails.
+    pub fn create_with_schedule(
+        ctx: Context<CreateVaultWithSchedule>,
+        identifier: u64,
+        name: [u8; 32],
+        tranches: Vec<Tranche>,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+        allow_public_deposits: bool,
+    ) -> Result<()> {
+        ctx.accounts.create(
+            identifier,
+            name,
+            tranches,
             cancel_authority,
+            transfer_authority,
             autopay,
+            allow_public_deposits,
             &ctx.bumps,
         )
     }
 
+    /// Adds funds to an existing vault. Extending the schedule of an autopay vault also funds its
+    /// escrow for the added payouts.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `amount_to_be_deposited` - The amount to add to the vault.
+    /// * `deposit_mode` - Whether the deposit extends the schedule or raises the remaining payouts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the deposit fails.
+    pub fn deposit(
+        ctx: Context<DepositVault>,
+        amount_to_be_deposited: u64,
+        deposit_mode: DepositMode,
+    ) -> Result<()> {
+        ctx.accounts.deposit(amount_to_be_deposited, deposit_mode)
+    }
+
     /// Disburses the vested amount from the vault.
     ///
+    /// Split vaults must pass the token account of each beneficiary as remaining accounts, in
+    /// the same order as the beneficiaries.
+    ///
     /// # Arguments
     ///
     /// * `ctx` - The context for the transaction.
@@ -202,8 +537,38 @@ pub mod valhalla {
     /// # Errors
     ///
     /// Returns an error if the disbursement fails.
-    pub fn disburse(ctx: Context<DisburseVault>) -> Result<()> {
-        ctx.accounts.disburse(&ctx.bumps)
+    pub fn disburse<'info>(ctx: Context<'_, '_, '_, 'info, DisburseVault<'info>>) -> Result<()> {
+        ctx.accounts.disburse(ctx.remaining_accounts, &ctx.bumps)
+    }
+
+    /// Returns the amount that can currently be disbursed from the vault, the next unlock time
+    /// and the remaining balance. Intended to be simulated by clients.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a scheduled vault is passed without its schedule, or a token vault
+    /// without its token account.
+    pub fn get_claimable(ctx: Context<GetClaimable>) -> Result<Claimable> {
+        ctx.accounts.get_claimable()
+    }
+
+    /// Migrates a vault created before the cliff, vesting mode and transfer fields were added to
+    /// the current layout. Anyone can migrate a vault; the signer pays for the extra rent.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `identifier` - The identifier of the vault.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vault has already been migrated.
+    pub fn migrate_vault(ctx: Context<MigrateVault>, _identifier: u64) -> Result<()> {
+        ctx.accounts.migrate()
     }
 
     /// Closes the vault, preventing further vesting and disbursements.
@@ -219,8 +584,183 @@ pub mod valhalla {
         ctx.accounts.close()
     }
 
+    /// Splits the payouts of the vault between multiple beneficiaries. The recipient must sign
+    /// along with the creator, and vaults without a cancel authority cannot be split.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `beneficiaries` - The recipients of the vault and their share in basis points.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the shares do not add up to 10000 basis points, the vault has already
+    /// paid out or the vault cannot be cancelled.
+    pub fn create_beneficiaries(
+        ctx: Context<CreateBeneficiaries>,
+        beneficiaries: Vec<Beneficiary>,
+    ) -> Result<()> {
+        ctx.accounts.create(beneficiaries)
+    }
+
+    /// Transfers the vault to a new recipient. The current recipient must sign, along with the
+    /// creator when the transfer authority is `Both`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signers do not satisfy the transfer authority of the vault.
+    pub fn transfer_recipient(ctx: Context<TransferRecipient>) -> Result<()> {
+        ctx.accounts.transfer_recipient()
+    }
+
     /// Cancels the vault, preventing further vesting and disbursements and returning the remaining funds to the cancel authority.
     ///
+    /// Split vaults must pass the token account of each beneficiary as remaining accounts, in
+    /// the same order as the beneficiaries.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vault cancellation fails.
+    pub fn cancel<'info>(ctx: Context<'_, '_, '_, 'info, CancelVault<'info>>) -> Result<()> {
+        ctx.accounts.cancel(ctx.remaining_accounts)
+    }
+
+    /// Creates a new vault that vests native SOL, held as lamports in the vault account.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `identifier` - The identifier of the vault.
+    /// * `name` - The name of the vault.
+    /// * `amount_to_be_vested` - The amount to be vested in the vault, in whole SOL.
+    /// * `total_vesting_duration` - The total duration of the vesting period.
+    /// * `start_date` - The start date of the vesting period.
+    /// * `payout_interval` - The interval at which the vested amount is disbursed.
+    /// * `cliff_date` - The date before which nothing can be disbursed.
+    /// * `cliff_amount` - The lump sum released on the first disbursement after the cliff date, in whole SOL.
+    /// * `vesting_mode` - Whether the vested amount is released per interval or continuously.
+    /// * `cancel_authority` - The authority to cancel the vault.
+    /// * `transfer_authority` - The authority that must sign to transfer the vault to a new recipient.
+    ///   The recipient always signs, so it cannot be `Creator`.
+    /// * `autopay` - Whether the vault should automatically disburse the vested amount, paid for
+    ///   from its autopay escrow.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vault creation fails, the amounts overflow or a payout is below the
+    /// rent-exempt minimum of a wallet.
+    pub fn create_sol(
+        ctx: Context<CreateSolVault>,
+        identifier: u64,
+        name: [u8; 32],
+        amount_to_be_vested: u64,
+        total_vesting_duration: u64,
+        start_date: u64,
+        payout_interval: u64,
+        cliff_date: u64,
+        cliff_amount: u64,
+        vesting_mode: VestingMode,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+    ) -> Result<()> {
+        ctx.accounts.create(
+            identifier,
+            name,
+            amount_to_be_vested,
+            total_vesting_duration,
+            start_date,
+            payout_interval,
+            cliff_date,
+            cliff_amount,
+            vesting_mode,
+            cancel_authority,
+            transfer_authority,
+            autopay,
+            &ctx.bumps,
+        )
+    }
+
+    /// Creates a new native vault with the amounts given in lamports, so fractional SOL amounts
+    /// can be vested.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    /// * `identifier` - The identifier of the vault.
+    /// * `name` - The name of the vault.
+    /// * `amount_to_be_vested` - The amount to be vested in the vault, in lamports.
+    /// * `total_vesting_duration` - The total duration of the vesting period.
+    /// * `start_date` - The start date of the vesting period.
+    /// * `payout_interval` - The interval at which the vested amount is disbursed.
+    /// * `cliff_date` - The date before which nothing can be disbursed.
+    /// * `cliff_amount` - The lump sum released on the first disbursement after the cliff date, in lamports.
+    /// * `vesting_mode` - Whether the vested amount is released per interval or continuously.
+    /// * `cancel_authority` - The authority to cancel the vault.
+    /// * `transfer_authority` - The authority that must sign to transfer the vault to a new recipient.
+    ///   The recipient always signs, so it cannot be `Creator`.
+    /// * `autopay` - Whether the vault should automatically disburse the vested amount, paid for
+    ///   from its autopay escrow.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vault creation fails or a payout is below the rent-exempt minimum of
+    /// a wallet.
+    pub fn create_sol_with_lamports(
+        ctx: Context<CreateSolVault>,
+        identifier: u64,
+        name: [u8; 32],
+        amount_to_be_vested: u64,
+        total_vesting_duration: u64,
+        start_date: u64,
+        payout_interval: u64,
+        cliff_date: u64,
+        cliff_amount: u64,
+        vesting_mode: VestingMode,
+        cancel_authority: Authority,
+        transfer_authority: Authority,
+        autopay: bool,
+    ) -> Result<()> {
+        ctx.accounts.create_with_lamports(
+            identifier,
+            name,
+            amount_to_be_vested,
+            total_vesting_duration,
+            start_date,
+            payout_interval,
+            cliff_date,
+            cliff_amount,
+            vesting_mode,
+            cancel_authority,
+            transfer_authority,
+            autopay,
+            &ctx.bumps,
+        )
+    }
+
+    /// Disburses the vested SOL from a native vault to the recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the disbursement fails.
+    pub fn disburse_sol(ctx: Context<DisburseSolVault>) -> Result<()> {
+        ctx.accounts.disburse(&ctx.bumps)
+    }
+
+    /// Cancels a native vault, paying the vested SOL to the recipient and returning the rest to the creator.
+    ///
     /// # Arguments
     ///
     /// * `ctx` - The context for the transaction.
@@ -228,7 +768,20 @@ pub mod valhalla {
     /// # Errors
     ///
     /// Returns an error if the vault cancellation fails.
-    pub fn cancel(ctx: Context<CancelVault>) -> Result<()> {
+    pub fn cancel_sol(ctx: Context<CancelSolVault>) -> Result<()> {
         ctx.accounts.cancel()
     }
+
+    /// Closes an expired and empty native vault.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context for the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vault closure fails.
+    pub fn close_sol(ctx: Context<CloseSolVault>) -> Result<()> {
+        ctx.accounts.close()
+    }
 }
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+/// Lamports set aside by the creator of an autopay vault to pay whoever disburses it. Whatever is
+/// left is refunded to the creator when the vault is cancelled or closed.
+#[account]
+pub struct AutopayEscrow {
+    pub vault: Pubkey,
+    pub lamports_per_disburse: u64,
+    pub number_of_payouts_paid: u64,
+}
+
+impl Space for AutopayEscrow {
+    const INIT_SPACE: usize = 8 + // discriminator
+            32 + // vault
+            8 + // lamports_per_disburse
+            8; // number_of_payouts_paid
+}
+
+impl AutopayEscrow {
+    /// Returns the lamports owed for the payouts completed since the escrow last paid out, given
+    /// the balance left in the escrow on top of its rent-exempt minimum. Disbursing again before
+    /// another payout completes pays nothing.
+    pub fn get_autopay_fee(&self, number_of_payouts_completed: u64, escrow_balance: u64) -> u64 {
+        self.lamports_per_disburse
+            .saturating_mul(number_of_payouts_completed.saturating_sub(self.number_of_payouts_paid))
+            .min(escrow_balance)
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, errors::ValhallaError, types::Beneficiary};
+
+#[account]
+pub struct Beneficiaries {
+    pub vault: Pubkey,
+    pub beneficiaries: Vec<Beneficiary>,
+}
+
+impl Space for Beneficiaries {
+    const INIT_SPACE: usize = 8 + // discriminator
+            32 + // vault
+            4 + (constants::MAX_BENEFICIARIES as usize * (32 + 8)); // beneficiaries
+}
+
+impl Beneficiaries {
+    /// Splits `amount` by share, giving any rounding remainder to the last beneficiary.
+    pub fn get_shares(&self, amount: u64) -> Result<Vec<u64>> {
+        let mut remaining_amount = amount;
+        let last_index = self.beneficiaries.len().saturating_sub(1);
+
+        self.beneficiaries
+            .iter()
+            .enumerate()
+            .map(|(index, beneficiary)| {
+                let share = match index == last_index {
+                    true => remaining_amount,
+                    false => (amount as u128)
+                        .checked_mul(beneficiary.share_basis_points as u128)
+                        .and_then(|share| share.checked_div(constants::MAX_BASIS_POINTS as u128))
+                        .ok_or(ValhallaError::MathOverflow)? as u64,
+                };
+                remaining_amount = remaining_amount
+                    .checked_sub(share)
+                    .ok_or(ValhallaError::MathOverflow)?;
+
+                Ok(share)
+            })
+            .collect()
+    }
+    /// Returns whether `key` receives a share of the vault.
+    pub fn is_beneficiary(&self, key: &Pubkey) -> bool {
+        self.beneficiaries
+            .iter()
+            .any(|beneficiary| beneficiary.recipient == *key)
+    }
+}
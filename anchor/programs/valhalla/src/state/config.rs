@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    constants, errors::ValhallaError, state::MintFeeOverride, FeeDiscountTier, MintExtensionPolicy,
+};
+
 #[account]
 pub struct Config {
     pub admin: Pubkey,
@@ -10,8 +14,127 @@ pub struct Config {
     pub autopay_multiplier: u64,
     pub token_fee_basis_points: u64,
     pub governance_token_amount: u64,
+    // Fields below were added after the first deploy. They are appended so that the config can be
+    // migrated in place with `migrate_config`.
+    pub pending_admin: Pubkey,
+    pub config_change_delay: u64,
+    pub mint_extension_policy: MintExtensionPolicy,
+    pub mint_extensions: u64,
+    pub allow_freeze_authority: bool,
+    pub paused: bool,
+    pub pause_flags: u8,
+    pub fee_discount_tiers: Vec<FeeDiscountTier>,
+    pub crank_reward_lamports: u64,
+    pub crank_reward_governance_token_amount: u64,
 }
 
 impl Space for Config {
-    const INIT_SPACE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 8 + 8;
+    const INIT_SPACE: usize = 8 + // discriminator
+            32 + // admin
+            32 + // dev_treasury
+            32 + // dao_treasury
+            32 + // governance_token_mint_key
+            8 + // dev_fee
+            8 + // autopay_multiplier
+            8 + // token_fee_basis_points
+            8 + // governance_token_amount
+            32 + // pending_admin
+            8 + // config_change_delay
+            1 + // mint_extension_policy
+            8 + // mint_extensions
+            1 + // allow_freeze_authority
+            1 + // paused
+            1 + // pause_flags
+            4 + (constants::MAX_FEE_DISCOUNT_TIERS as usize * (8 + 8)) + // fee_discount_tiers
+            8 + // crank_reward_lamports
+            8; // crank_reward_governance_token_amount
+}
+
+impl Config {
+    /// The size of the config created before the admin handover, config change delay, mint policy,
+    /// pause, fee discount and crank reward fields were added.
+    pub const LEGACY_SPACE: usize = 8 + // discriminator
+            32 + // admin
+            32 + // dev_treasury
+            32 + // dao_treasury
+            32 + // governance_token_mint_key
+            8 + // dev_fee
+            8 + // autopay_multiplier
+            8 + // token_fee_basis_points
+            8; // governance_token_amount
+
+    /// Checks whether the instructions behind `pause_flag` are paused, either on their own or
+    /// because the whole program is paused.
+    pub fn is_paused(&self, pause_flag: u8) -> bool {
+        self.paused || self.pause_flags & pause_flag != 0
+    }
+
+    /// Returns the token fee basis points and dev fee charged for a mint. An override can only
+    /// lower the fees, so it is capped at the fees in the config.
+    pub fn get_mint_fees(&self, mint_fee_override: Option<&MintFeeOverride>) -> (u64, u64) {
+        match mint_fee_override {
+            Some(mint_fee_override) => {
+                let (token_fee_basis_points, dev_fee) = mint_fee_override.get_fees();
+                (
+                    token_fee_basis_points.min(self.token_fee_basis_points),
+                    dev_fee.min(self.dev_fee),
+                )
+            }
+            None => (self.token_fee_basis_points, self.dev_fee),
+        }
+    }
+
+    /// Returns the token fee basis points and dev fee after the discount of the highest tier that
+    /// `governance_token_balance` qualifies for.
+    pub fn get_discounted_fees(
+        &self,
+        token_fee_basis_points: u64,
+        dev_fee: u64,
+        governance_token_balance: u64,
+    ) -> Result<(u64, u64)> {
+        let discount_basis_points = self
+            .fee_discount_tiers
+            .iter()
+            .filter(|tier| governance_token_balance >= tier.min_governance_token_amount)
+            .map(|tier| tier.discount_basis_points)
+            .max()
+            .unwrap_or(0);
+
+        let discount = |fee: u64| -> Result<u64> {
+            Ok((fee as u128)
+                .checked_mul(
+                    constants::MAX_BASIS_POINTS.saturating_sub(discount_basis_points) as u128,
+                )
+                .and_then(|fee| fee.checked_div(constants::MAX_BASIS_POINTS as u128))
+                .ok_or(ValhallaError::MathOverflow)? as u64)
+        };
+
+        Ok((discount(token_fee_basis_points)?, discount(dev_fee)?))
+    }
+
+    /// Returns the lamports paid from the autopay escrow of a vault for each completed payout,
+    /// covering the transaction and the rent of a token account, plus the crank reward. Disburses
+    /// that do not complete a payout are not paid, so the crank reward cannot be farmed.
+    pub fn get_autopay_lamports_per_disburse(&self, token_account_rent: u64) -> Result<u64> {
+        Ok(constants::AUTOPAY_FEE_PER_TX
+            .checked_add(token_account_rent)
+            .and_then(|fee| fee.checked_mul(self.autopay_multiplier))
+            .and_then(|fee| fee.checked_add(self.crank_reward_lamports))
+            .ok_or(ValhallaError::MathOverflow)?)
+    }
+
+    /// Checks whether vaults can be created for a mint.
+    ///
+    /// `mint_extensions` is a bitmask where bit `n` is set when the mint has the Token-2022
+    /// extension with discriminant `n`.
+    pub fn is_mint_allowed(&self, mint_extensions: u64, has_freeze_authority: bool) -> bool {
+        if has_freeze_authority && !self.allow_freeze_authority {
+            return false;
+        }
+
+        match self.mint_extension_policy {
+            MintExtensionPolicy::Denylist => mint_extensions & self.mint_extensions == 0,
+            MintExtensionPolicy::Allowlist => mint_extensions & !self.mint_extensions == 0,
+        }
+    }
 }
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::ValhallaError;
+
+/// Cumulative fees collected for a mint. Token fees are held in the fee vault token account of
+/// the mint, while SOL fees are held as lamports in this account until they are withdrawn.
+#[account]
+pub struct FeeStats {
+    pub mint: Pubkey,
+    pub total_token_fees: u64,
+    pub total_sol_fees: u64,
+    pub withdrawn_token_fees: u64,
+    pub withdrawn_sol_fees: u64,
+}
+
+impl Space for FeeStats {
+    const INIT_SPACE: usize = 8 + // discriminator
+            32 + // mint
+            8 + // total_token_fees
+            8 + // total_sol_fees
+            8 + // withdrawn_token_fees
+            8; // withdrawn_sol_fees
+}
+
+impl FeeStats {
+    pub fn add_token_fees(&mut self, amount: u64) -> Result<()> {
+        self.total_token_fees = self
+            .total_token_fees
+            .checked_add(amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn add_sol_fees(&mut self, amount: u64) -> Result<()> {
+        self.total_sol_fees = self
+            .total_sol_fees
+            .checked_add(amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Returns the token fees collected since the last withdrawal.
+    pub fn get_pending_token_fees(&self) -> Result<u64> {
+        Ok(self
+            .total_token_fees
+            .checked_sub(self.withdrawn_token_fees)
+            .ok_or(ValhallaError::MathOverflow)?)
+    }
+
+    /// Returns the SOL fees collected since the last withdrawal.
+    pub fn get_pending_sol_fees(&self) -> Result<u64> {
+        Ok(self
+            .total_sol_fees
+            .checked_sub(self.withdrawn_sol_fees)
+            .ok_or(ValhallaError::MathOverflow)?)
+    }
+}
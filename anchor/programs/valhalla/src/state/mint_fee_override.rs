@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// Fees negotiated for a single mint, used when creating vaults in place of the fees in the config.
+#[account]
+pub struct MintFeeOverride {
+    pub mint: Pubkey,
+    pub token_fee_basis_points: u64,
+    pub dev_fee: u64,
+    pub fee_waived: bool,
+}
+
+impl Space for MintFeeOverride {
+    const INIT_SPACE: usize = 8 + // discriminator
+            32 + // mint
+            8 + // token_fee_basis_points
+            8 + // dev_fee
+            1; // fee_waived
+}
+
+impl MintFeeOverride {
+    /// Returns the override stored in `account`, or `None` if no override has been set for the
+    /// mint.
+    pub fn try_load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &account.data.borrow()[..],
+        )?))
+    }
+
+    /// Returns the token fee basis points and dev fee charged for the mint.
+    pub fn get_fees(&self) -> (u64, u64) {
+        match self.fee_waived {
+            true => (0, 0),
+            false => (self.token_fee_basis_points, self.dev_fee),
+        }
+    }
+}
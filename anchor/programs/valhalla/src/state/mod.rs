@@ -1,5 +1,17 @@
+mod autopay_escrow;
+mod beneficiaries;
 mod config;
+mod fee_stats;
+mod mint_fee_override;
+mod pending_config_change;
+mod schedule;
 mod vault;
 
+pub use autopay_escrow::*;
+pub use beneficiaries::*;
 pub use config::*;
+pub use fee_stats::*;
+pub use mint_fee_override::*;
+pub use pending_config_change::*;
+pub use schedule::*;
 pub use vault::*;
@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+use crate::ConfigChange;
+
+#[account]
+pub struct PendingConfigChange {
+    pub config_change: ConfigChange,
+    pub eta: u64,
+}
+
+impl Space for PendingConfigChange {
+    const INIT_SPACE: usize = 8 + // discriminator
+            1 + 32 + // config_change
+            8; // eta
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants, errors::ValhallaError, types::Tranche};
+
+#[account]
+pub struct Schedule {
+    pub vault: Pubkey,
+    pub tranches: Vec<Tranche>,
+}
+
+impl Space for Schedule {
+    const INIT_SPACE: usize = 8 + // discriminator
+            32 + // vault
+            4 + (constants::MAX_TRANCHES as usize * (8 + 8)); // tranches
+}
+
+impl Schedule {
+    /// Returns the total amount of every tranche unlocked at `current_time`.
+    pub fn get_vested_amount(&self, current_time: u64) -> Result<u64> {
+        Ok(self
+            .tranches
+            .iter()
+            .filter(|tranche| tranche.timestamp <= current_time)
+            .try_fold(0u64, |total, tranche| total.checked_add(tranche.amount))
+            .ok_or(ValhallaError::MathOverflow)?)
+    }
+
+    /// Returns the number of tranches unlocked at `current_time`.
+    pub fn get_number_of_tranches_unlocked(&self, current_time: u64) -> u64 {
+        self.tranches
+            .iter()
+            .filter(|tranche| tranche.timestamp <= current_time)
+            .count() as u64
+    }
+}
@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::types::Authority;
+use crate::{
+    constants,
+    errors::ValhallaError,
+    state::Schedule,
+    types::{Authority, VestingMode},
+};
 
 #[account]
 pub struct Vault {
@@ -20,6 +25,17 @@ pub struct Vault {
     pub cancel_authority: Authority,
     pub autopay: bool,
     pub token_account_bump: u8,
+    // Fields below were added after the first deploy. They are appended so that vaults created
+    // before them can be migrated in place with `migrate_vault`.
+    pub cliff_date: u64,
+    pub cliff_amount: u64,
+    pub is_cliff_payment_disbursed: bool,
+    pub total_withdrawn: u64,
+    pub vesting_mode: VestingMode,
+    pub transfer_authority: Authority,
+    pub allow_public_deposits: bool,
+    pub is_split: bool,
+    pub is_native: bool,
 }
 
 impl Space for Vault {
@@ -39,17 +55,79 @@ impl Space for Vault {
             8 + // number_of_payments_made
             1 + // cancel_authority
             1 + // autopay
-            1; // token_account_bump
+            1 + // token_account_bump
+            8 + // cliff_date
+            8 + // cliff_amount
+            1 + // is_cliff_payment_disbursed
+            8 + // total_withdrawn
+            1 + // vesting_mode
+            1 + // transfer_authority
+            1 + // allow_public_deposits
+            1 + // is_split
+            1; // is_native
 }
 
 impl<'info> Vault {
+    /// The size of vaults created before the cliff, vesting mode and transfer fields were added.
+    pub const LEGACY_SPACE: usize = 8 + // discriminator
+            8 + // identifier
+            32 + // name
+            32 + // creator
+            32 + // recipient
+            32 + // mint
+            8 + // total_vesting_duration
+            8 + // created_timestamp
+            8 + // start_date
+            8 + // last_payment_timestamp
+            8 + // initial_deposit_amount
+            8 + // total_number_of_payouts
+            8 + // payout_interval
+            8 + // number_of_payments_made
+            1 + // cancel_authority
+            1 + // autopay
+            1; // token_account_bump
+
+    /// Validates the parameters a vault is created with.
+    pub fn validate_vesting_parameters(
+        amount: u64,
+        start_date: u64,
+        total_vesting_duration: u64,
+        payout_interval: u64,
+        current_time: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ValhallaError::InvalidAmount);
+        require!(
+            payout_interval > 0 && payout_interval <= total_vesting_duration,
+            ValhallaError::InvalidPayoutInterval
+        );
+        require!(
+            start_date.saturating_add(constants::START_DATE_GRACE_PERIOD) >= current_time,
+            ValhallaError::InvalidStartDate
+        );
+
+        Ok(())
+    }
+
+    /// Validates the authority a vault is created with. The recipient must sign every transfer,
+    /// so a vault that only the creator could transfer would never be transferable.
+    pub fn validate_transfer_authority(transfer_authority: &Authority) -> Result<()> {
+        require!(
+            *transfer_authority != Authority::Creator,
+            ValhallaError::InvalidTransferAuthority
+        );
+
+        Ok(())
+    }
+
     pub fn is_locked(&self, current_time: u64) -> Result<bool> {
-        match self.start_date > current_time {
+        match self.start_date > current_time || self.cliff_date > current_time {
             true => Ok(true),
+            false if self.is_cliff_payment_due() => Ok(false),
+            false if self.vesting_mode != VestingMode::Interval => Ok(false),
             false => {
                 let time_elapsed = current_time
                     .checked_sub(self.last_payment_timestamp)
-                    .unwrap();
+                    .ok_or(ValhallaError::MathOverflow)?;
 
                 Ok(time_elapsed < self.payout_interval)
             }
@@ -57,17 +135,216 @@ impl<'info> Vault {
     }
 
     pub fn is_expired(&self, current_time: u64) -> Result<bool> {
+        Ok(self.get_end_date()? <= current_time)
+    }
+
+    pub fn get_end_date(&self) -> Result<u64> {
         Ok(self
             .start_date
             .checked_add(self.total_vesting_duration)
-            .unwrap()
-            <= current_time)
+            .ok_or(ValhallaError::MathOverflow)?)
+    }
+
+    /// Returns the number of whole payout intervals that have elapsed since the
+    /// last payment, capped at the number of payouts remaining. Only interval
+    /// vaults have payouts due.
+    pub fn get_number_of_payouts_due(&self, current_time: u64) -> Result<u64> {
+        if self.vesting_mode != VestingMode::Interval {
+            return Ok(0);
+        }
+
+        let time_elapsed = current_time.saturating_sub(self.last_payment_timestamp);
+        let remaining_payouts = self
+            .total_number_of_payouts
+            .saturating_sub(self.number_of_payments_made);
+
+        Ok(time_elapsed
+            .checked_div(self.payout_interval)
+            .ok_or(ValhallaError::MathOverflow)?
+            .min(remaining_payouts))
+    }
+
+    /// Returns the number of payouts completed by `current_time`. Continuous vaults vest every
+    /// second, so each elapsed payout interval after the cliff counts as a payout.
+    pub fn get_number_of_payouts_completed(&self, current_time: u64) -> Result<u64> {
+        let number_of_payouts_completed = match self.vesting_mode {
+            VestingMode::Continuous if self.is_expired(current_time)? => {
+                self.total_number_of_payouts
+            }
+            VestingMode::Continuous => current_time
+                .saturating_sub(self.start_date.max(self.cliff_date))
+                .checked_div(self.payout_interval)
+                .ok_or(ValhallaError::MathOverflow)?,
+            VestingMode::Interval | VestingMode::Scheduled => self.number_of_payments_made,
+        };
+
+        Ok(number_of_payouts_completed.min(self.total_number_of_payouts))
+    }
+
+    pub fn is_cliff_payment_due(&self) -> bool {
+        self.cliff_amount > 0 && !self.is_cliff_payment_disbursed
     }
 
+    /// Returns the amount released per payout, spreading the linear amount that has not been
+    /// paid out yet over the remaining payouts.
     pub fn get_amount_per_payout(&self) -> Result<u64> {
+        let remaining_payouts = self
+            .total_number_of_payouts
+            .saturating_sub(self.number_of_payments_made);
+
+        Ok(self
+            .get_remaining_linear_amount()?
+            .checked_div(remaining_payouts)
+            .unwrap_or(0))
+    }
+
+    /// Returns the amount released by the next `payouts` payouts. The remainder of dividing the
+    /// linear amount by the number of payouts is carried forward, so the final payout empties the
+    /// vault and every earlier payout differs by at most one base unit.
+    pub fn get_amount_for_payouts(&self, payouts: u64) -> Result<u64> {
+        let remaining_payouts = self
+            .total_number_of_payouts
+            .saturating_sub(self.number_of_payments_made);
+        if remaining_payouts == 0 {
+            return Ok(0);
+        }
+
+        let amount = (self.get_remaining_linear_amount()? as u128)
+            .checked_mul(payouts.min(remaining_payouts) as u128)
+            .ok_or(ValhallaError::MathOverflow)?
+            .checked_div(remaining_payouts as u128)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        Ok(amount as u64)
+    }
+
+    pub fn get_remaining_linear_amount(&self) -> Result<u64> {
+        let cliff_amount_withdrawn = match self.is_cliff_payment_disbursed {
+            true => self.cliff_amount,
+            false => 0,
+        };
+        let linear_amount_withdrawn = self.total_withdrawn.saturating_sub(cliff_amount_withdrawn);
+
         Ok(self
             .initial_deposit_amount
-            .checked_div(self.total_number_of_payouts)
-            .unwrap())
+            .checked_sub(self.cliff_amount)
+            .ok_or(ValhallaError::MathOverflow)?
+            .saturating_sub(linear_amount_withdrawn))
+    }
+
+    /// Returns the amount vested up to `current_time`, accruing the linear
+    /// portion every second between the cliff (or start) date and the end date.
+    pub fn get_continuous_vested_amount(&self, current_time: u64) -> Result<u64> {
+        if self.is_expired(current_time)? {
+            return Ok(self.initial_deposit_amount);
+        }
+
+        let linear_start_date = self.start_date.max(self.cliff_date);
+        if current_time < linear_start_date {
+            return Ok(0);
+        }
+
+        let linear_amount = self
+            .initial_deposit_amount
+            .checked_sub(self.cliff_amount)
+            .ok_or(ValhallaError::MathOverflow)?;
+        let elapsed = current_time
+            .checked_sub(linear_start_date)
+            .ok_or(ValhallaError::MathOverflow)?;
+        let duration = self
+            .get_end_date()?
+            .checked_sub(linear_start_date)
+            .ok_or(ValhallaError::MathOverflow)?;
+
+        let vested_linear_amount = (linear_amount as u128)
+            .checked_mul(elapsed as u128)
+            .ok_or(ValhallaError::MathOverflow)?
+            .checked_div(duration as u128)
+            .ok_or(ValhallaError::MathOverflow)? as u64;
+
+        Ok(vested_linear_amount
+            .checked_add(self.cliff_amount)
+            .ok_or(ValhallaError::MathOverflow)?)
+    }
+
+    /// Returns the amount that can be disbursed at `current_time` out of `vault_balance`.
+    /// Scheduled vaults must pass their schedule.
+    pub fn get_claimable_amount(
+        &self,
+        current_time: u64,
+        vault_balance: u64,
+        schedule: Option<&Schedule>,
+    ) -> Result<u64> {
+        let amount = match self.vesting_mode {
+            // Everything vested so far that has not been withdrawn yet.
+            VestingMode::Continuous => self
+                .get_continuous_vested_amount(current_time)?
+                .saturating_sub(self.total_withdrawn),
+            // Every tranche unlocked so far that has not been withdrawn yet.
+            VestingMode::Scheduled => Self::get_schedule(schedule)?
+                .get_vested_amount(current_time)?
+                .saturating_sub(self.total_withdrawn),
+            // Every interval that has elapsed, plus the cliff lump sum if it is still owed.
+            VestingMode::Interval => {
+                let cliff_amount = match self.is_cliff_payment_due() {
+                    true => self.cliff_amount,
+                    false => 0,
+                };
+
+                self.get_amount_for_payouts(self.get_number_of_payouts_due(current_time)?)?
+                    .checked_add(cliff_amount)
+                    .ok_or(ValhallaError::MathOverflow)?
+            }
+        }
+        .min(vault_balance);
+
+        match self.is_expired(current_time)? {
+            true => Ok(vault_balance),
+            false => Ok(amount),
+        }
+    }
+
+    /// Returns the next time at which more tokens unlock, or zero if nothing is left to unlock.
+    pub fn get_next_unlock_time(
+        &self,
+        current_time: u64,
+        schedule: Option<&Schedule>,
+    ) -> Result<u64> {
+        if self.is_expired(current_time)? {
+            return Ok(0);
+        }
+
+        let linear_start_date = self.start_date.max(self.cliff_date);
+
+        let next_unlock_time = match self.vesting_mode {
+            VestingMode::Continuous => linear_start_date.max(current_time),
+            VestingMode::Scheduled => Self::get_schedule(schedule)?
+                .tranches
+                .iter()
+                .map(|tranche| tranche.timestamp)
+                .find(|timestamp| *timestamp > current_time)
+                .unwrap_or(0),
+            VestingMode::Interval if current_time < linear_start_date => linear_start_date,
+            VestingMode::Interval => self
+                .last_payment_timestamp
+                .checked_add(
+                    self.get_number_of_payouts_due(current_time)?
+                        .checked_add(1)
+                        .ok_or(ValhallaError::MathOverflow)?
+                        .checked_mul(self.payout_interval)
+                        .ok_or(ValhallaError::MathOverflow)?,
+                )
+                .ok_or(ValhallaError::MathOverflow)?
+                .min(self.get_end_date()?),
+        };
+
+        Ok(next_unlock_time)
+    }
+
+    fn get_schedule(schedule: Option<&Schedule>) -> Result<&Schedule> {
+        match schedule {
+            Some(schedule) => Ok(schedule),
+            None => Err(ValhallaError::InvalidSchedule.into()),
+        }
     }
 }
@@ -7,3 +7,65 @@ pub enum Authority {
     Recipient,
     Both,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum VestingMode {
+    Interval,
+    Continuous,
+    Scheduled,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum DepositMode {
+    ExtendSchedule,
+    IncreasePayouts,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum MintExtensionPolicy {
+    Denylist,
+    Allowlist,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum ConfigChange {
+    DevFee(u64),
+    TokenFeeBasisPoints(u64),
+    GovernanceTokenAmount(u64),
+    DaoTreasury(Pubkey),
+    ConfigChangeDelay(u64),
+    CrankRewardLamports(u64),
+    CrankRewardGovernanceTokenAmount(u64),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct FeeDiscountTier {
+    pub min_governance_token_amount: u64,
+    pub discount_basis_points: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct Tranche {
+    pub timestamp: u64,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct Beneficiary {
+    pub recipient: Pubkey,
+    pub share_basis_points: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct BatchVault {
+    pub identifier: u64,
+    pub amount_to_be_vested: u64,
+    pub cliff_amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct Claimable {
+    pub amount: u64,
+    pub next_unlock_time: u64,
+    pub remaining_balance: u64,
+}
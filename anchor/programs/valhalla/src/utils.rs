@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_fee::{instruction as transfer_fee_instruction, TransferFeeConfig},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+    },
+    token_interface::{transfer_checked, TransferChecked},
+};
+use solana_program::program::invoke_signed;
+
+use crate::{constants, errors::ValhallaError};
+
+/// Returns the transfer fee config of a Token-2022 mint, if it has the transfer fee extension.
+fn get_transfer_fee_config(mint: &AccountInfo) -> Result<Option<TransferFeeConfig>> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    Ok(mint_state
+        .get_extension::<TransferFeeConfig>()
+        .ok()
+        .copied())
+}
+
+/// Returns the lamports held by a native vault on top of its rent-exempt minimum.
+pub fn get_native_balance(account: &AccountInfo) -> Result<u64> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(account.data_len());
+
+    Ok(account.lamports().saturating_sub(rent_exempt_minimum))
+}
+
+/// Returns a bitmask of the Token-2022 extensions on a mint, where bit `n` is set when the mint
+/// has the extension with discriminant `n`.
+pub fn get_mint_extensions(mint: &AccountInfo) -> Result<u64> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(0);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+
+    Ok(mint_state
+        .get_extension_types()?
+        .into_iter()
+        .map(u16::from)
+        .filter(|extension| *extension < u64::BITS as u16)
+        .fold(0, |mint_extensions, extension| {
+            mint_extensions | 1 << extension
+        }))
+}
+
+/// Returns the token fee charged on `amount`, multiplying in u128 so large amounts cannot
+/// overflow.
+pub fn get_token_fee_amount(amount: u64, token_fee_basis_points: u64) -> Result<u64> {
+    Ok((amount as u128)
+        .checked_mul(token_fee_basis_points as u128)
+        .and_then(|amount| amount.checked_div(constants::MAX_BASIS_POINTS as u128))
+        .and_then(|amount| u64::try_from(amount).ok())
+        .ok_or(ValhallaError::MathOverflow)?)
+}
+
+/// Returns the amount withheld by the mint when transferring `amount` in the current epoch.
+pub fn get_transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    let fee = match get_transfer_fee_config(mint)? {
+        Some(transfer_fee_config) => transfer_fee_config
+            .calculate_epoch_fee(Clock::get()?.epoch, amount)
+            .ok_or(ValhallaError::MathOverflow)?,
+        None => 0,
+    };
+
+    Ok(fee)
+}
+
+/// Transfers `amount` with `transfer_checked_with_fee` when the mint has the transfer fee
+/// extension, falling back to `transfer_checked` otherwise. Returns the fee that was withheld.
+pub fn transfer_checked_with_fee<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<u64> {
+    let transfer_fee_config = match get_transfer_fee_config(&ctx.accounts.mint)? {
+        Some(transfer_fee_config) => transfer_fee_config,
+        None => {
+            transfer_checked(ctx, amount, decimals)?;
+            return Ok(0);
+        }
+    };
+
+    let fee = transfer_fee_config
+        .calculate_epoch_fee(Clock::get()?.epoch, amount)
+        .ok_or(ValhallaError::MathOverflow)?;
+    let ix = transfer_fee_instruction::transfer_checked_with_fee(
+        ctx.program.key,
+        ctx.accounts.from.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.to.key,
+        ctx.accounts.authority.key,
+        &[],
+        amount,
+        decimals,
+        fee,
+    )?;
+
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.from,
+            ctx.accounts.mint,
+            ctx.accounts.to,
+            ctx.accounts.authority,
+        ],
+        ctx.signer_seeds,
+    )?;
+
+    Ok(fee)
+}
+
+/// Moves any transfer fees withheld in `account` to the mint so the account can be closed.
+pub fn harvest_withheld_tokens<'info>(
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+) -> Result<()> {
+    if get_transfer_fee_config(&mint)?.is_none() {
+        return Ok(());
+    }
+
+    let ix = transfer_fee_instruction::harvest_withheld_tokens_to_mint(
+        token_program.key,
+        mint.key,
+        &[account.key],
+    )?;
+
+    invoke_signed(&ix, &[mint, account], &[])?;
+
+    Ok(())
+}
@@ -15,9 +15,11 @@ import {
 } from "@solana/spl-token";
 import {
   Authority,
+  VestingMode,
   confirm,
   getAuthority,
   getName,
+  getVestingMode,
   sleep,
 } from "../tests/utils/utils";
 import {
@@ -29,9 +31,9 @@ import {
   Transaction,
 } from "@solana/web3.js";
 
-import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 import { Valhalla } from "../target/types/valhalla";
 import generate from "project-name-generator";
+import { getCreateAccounts } from "../tests/utils/getAccounts";
 import { getPDAs } from "../tests/utils/getPDAs";
 import one from "../.keys/creator.json";
 import { randomBytes } from "crypto";
@@ -50,7 +52,6 @@ async function spl() {
   anchor.setProvider(provider);
 
   const connection = provider.connection;
-  const wallet = provider.wallet as NodeWallet;
   const userOne = Keypair.fromSecretKey(new Uint8Array(one));
   const userTwo = Keypair.fromSecretKey(new Uint8Array(two));
   console.log("User one: ", userOne.publicKey.toBase58());
@@ -78,20 +79,6 @@ async function spl() {
     userTwo.publicKey
   );
 
-  const daoTreasuryAtaUserOne = await getOrCreateAssociatedTokenAccount(
-    provider.connection,
-    userOne,
-    mintUserOne,
-    wallet.publicKey
-  );
-
-  const daoTreasuryAtaUserTwo = await getOrCreateAssociatedTokenAccount(
-    provider.connection,
-    userTwo,
-    mintUserTwo,
-    wallet.publicKey
-  );
-
   const userOneGovernanceAta = await getOrCreateAssociatedTokenAccount(
     provider.connection,
     userOne,
@@ -111,8 +98,6 @@ async function spl() {
     const creator = i % 2 === 0 ? userOne : userTwo;
     const recipient = i % 2 === 0 ? userTwo : userOne;
     const creatorAta = i % 2 === 0 ? userOneAta : userTwoAta;
-    const daoTreasuryAta =
-      i % 2 === 0 ? daoTreasuryAtaUserOne : daoTreasuryAtaUserTwo;
     const mint = i % 2 === 0 ? mintUserOne : mintUserTwo;
     const autopay = i % 3 === 0;
     const creatorGovernanceAta =
@@ -124,13 +109,10 @@ async function spl() {
       recipient,
       creatorAta,
       creatorGovernanceAta,
-      daoTreasuryAta,
       mint,
-      configAccount.governanceTokenMintKey,
       autopay,
       i,
       program,
-      wallet,
       TOKEN_PROGRAM_ID
     );
 
@@ -143,7 +125,6 @@ async function token2022() {
   anchor.setProvider(provider);
 
   const connection = provider.connection;
-  const wallet = provider.wallet as NodeWallet;
   const userOne = Keypair.fromSecretKey(new Uint8Array(one));
   const userTwo = Keypair.fromSecretKey(new Uint8Array(two));
 
@@ -187,30 +168,6 @@ async function token2022() {
     ASSOCIATED_TOKEN_PROGRAM_ID
   );
 
-  const daoTreasuryAtaUserOne = await getOrCreateAssociatedTokenAccount(
-    provider.connection,
-    userOne,
-    mintUserOne,
-    wallet.publicKey,
-    false,
-    undefined,
-    undefined,
-    TOKEN_2022_PROGRAM_ID,
-    ASSOCIATED_TOKEN_PROGRAM_ID
-  );
-
-  const daoTreasuryAtaUserTwo = await getOrCreateAssociatedTokenAccount(
-    provider.connection,
-    userTwo,
-    mintUserTwo,
-    wallet.publicKey,
-    false,
-    undefined,
-    undefined,
-    TOKEN_2022_PROGRAM_ID,
-    ASSOCIATED_TOKEN_PROGRAM_ID
-  );
-
   const userOneGovernanceAta = await getOrCreateAssociatedTokenAccount(
     provider.connection,
     userOne,
@@ -240,8 +197,6 @@ async function token2022() {
     const creator = i % 2 === 0 ? userOne : userTwo;
     const recipient = i % 2 === 0 ? userTwo : userOne;
     const creatorAta = i % 2 === 0 ? userOneAta : userTwoAta;
-    const daoTreasuryAta =
-      i % 2 === 0 ? daoTreasuryAtaUserOne : daoTreasuryAtaUserTwo;
     const mint = i % 2 === 0 ? mintUserOne : mintUserTwo;
     const autopay = i % 2 === 0 ? true : false;
     const creatorGovernanceAta =
@@ -253,13 +208,10 @@ async function token2022() {
       recipient,
       creatorAta,
       creatorGovernanceAta,
-      daoTreasuryAta,
       mint,
-      configAccount.governanceTokenMintKey,
       autopay,
       i,
       program,
-      wallet,
       TOKEN_2022_PROGRAM_ID
     );
 
@@ -273,13 +225,10 @@ async function create(
   recipient: Keypair,
   creatorAta: Account,
   creatorGovernanceAta: Account,
-  daoTreasuryAta: Account,
   mint: PublicKey,
-  governanceTokenMint: PublicKey,
   autopay: boolean,
   i: number,
   program: anchor.Program<Valhalla>,
-  wallet: NodeWallet,
   tokenProgram: PublicKey
 ) {
   const identifier = new anchor.BN(randomBytes(8));
@@ -332,7 +281,12 @@ async function create(
       cancelAuthority = getAuthority(Authority.Neither, program);
   }
 
-  const pdas = getPDAs(program.programId, identifier, creator.publicKey, mint);
+  const { autopayEscrow } = getPDAs(
+    program.programId,
+    identifier,
+    creator.publicKey,
+    mint
+  );
 
   const tx = await program.methods
     .create(
@@ -342,25 +296,26 @@ async function create(
       totalVestingDuration,
       startDate,
       payoutInterval,
+      new anchor.BN(0),
+      new anchor.BN(0),
+      getVestingMode(VestingMode.Interval, program),
       cancelAuthority,
-      autopay
+      getAuthority(Authority.Neither, program),
+      autopay,
+      false
     )
     .accounts({
-      creator: creator.publicKey,
-      recipient: recipient.publicKey,
-      devTreasury: wallet.publicKey,
-      daoTreasury: wallet.publicKey,
-      config: pdas.config,
-      vault: pdas.vault,
-      vaultAta: pdas.vaultAta,
-      daoTreasuryAta: daoTreasuryAta.address,
-      creatorAta: creatorAta.address,
-      creatorGovernanceAta: creatorGovernanceAta.address,
-      mint,
-      governanceTokenMint,
-      governanceTokenProgram: TOKEN_PROGRAM_ID,
-      tokenProgram,
-      systemProgram: SystemProgram.programId,
+      ...getCreateAccounts({
+        programId: program.programId,
+        identifier,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        mint,
+        creatorAta: creatorAta.address,
+        creatorGovernanceAta: creatorGovernanceAta.address,
+        tokenProgram,
+      }),
+      autopayEscrow: autopay ? autopayEscrow : null,
     })
     .signers([creator])
     .rpc();
@@ -21,6 +21,7 @@ const devFee = new anchor.BN(0.05 * LAMPORTS_PER_SOL);
 const autopayMultiplier = new anchor.BN(3);
 const tokenFeeBasisPoints = new anchor.BN(50);
 const governanceTokenAmount = new anchor.BN(1 * LAMPORTS_PER_SOL);
+const configChangeDelay = new anchor.BN(2 * 24 * 60 * 60);
 const tokenName = "Odin";
 const tokenSymbol = "ODIN";
 const tokenURI =
@@ -70,7 +71,8 @@ const main = async () => {
       devFee,
       autopayMultiplier,
       tokenFeeBasisPoints,
-      governanceTokenAmount
+      governanceTokenAmount,
+      configChangeDelay
     )
     .accounts({
       admin: wallet.publicKey,
@@ -111,6 +113,10 @@ const main = async () => {
     "🪙 Reward Token Amount:",
     configAccount.governanceTokenAmount.toNumber()
   );
+  console.log(
+    "⏳ Config Change Delay:",
+    configAccount.configChangeDelay.toNumber()
+  );
 };
 
 main()
@@ -0,0 +1,129 @@
+import * as anchor from "@coral-xyz/anchor";
+import type * as beet from "@metaplex-foundation/beet";
+
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+
+import { GOVERNANCE_TOKEN_MINT_SEED } from "./utils";
+import { PublicKey } from "@solana/web3.js";
+import { getFeePDAs, getPDAs } from "./getPDAs";
+
+export interface CreateAccountsArgs {
+  programId: PublicKey;
+  identifier: beet.bignum;
+  creator: PublicKey;
+  recipient: PublicKey;
+  mint: PublicKey;
+  creatorAta: PublicKey;
+  creatorGovernanceAta: PublicKey;
+  tokenProgram?: PublicKey;
+}
+
+export interface DisburseAccountsArgs {
+  programId: PublicKey;
+  identifier: beet.bignum;
+  signer: PublicKey;
+  creator: PublicKey;
+  recipient: PublicKey;
+  mint: PublicKey;
+  signerGovernanceAta: PublicKey;
+  creatorGovernanceAta: PublicKey;
+  recipientAta: PublicKey;
+  tokenProgram?: PublicKey;
+}
+
+export function getGovernanceTokenMint(programId: PublicKey): PublicKey {
+  const [governanceTokenMint] = PublicKey.findProgramAddressSync(
+    [GOVERNANCE_TOKEN_MINT_SEED],
+    programId
+  );
+
+  return governanceTokenMint;
+}
+
+/**
+ * Returns the accounts shared by `create`, `createWithBaseUnits` and `createWithSchedule`.
+ * Optional accounts, such as the schedule or autopay escrow, are added by the caller.
+ */
+export function getCreateAccounts({
+  programId,
+  identifier,
+  creator,
+  recipient,
+  mint,
+  creatorAta,
+  creatorGovernanceAta,
+  tokenProgram = TOKEN_2022_PROGRAM_ID,
+}: CreateAccountsArgs) {
+  const { config, vault, vaultAta } = getPDAs(
+    programId,
+    identifier,
+    creator,
+    mint
+  );
+  const { feeVault, feeStats, mintFeeOverride } = getFeePDAs(programId, mint);
+
+  return {
+    creator,
+    recipient,
+    config,
+    vault,
+    vaultAta,
+    feeVault,
+    feeStats,
+    mintFeeOverride,
+    creatorAta,
+    creatorGovernanceAta,
+    mint,
+    governanceTokenMint: getGovernanceTokenMint(programId),
+    tokenProgram,
+    governanceTokenProgram: TOKEN_PROGRAM_ID,
+    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    systemProgram: anchor.web3.SystemProgram.programId,
+  };
+}
+
+/**
+ * Returns the accounts of `disburse`. Optional accounts, such as the schedule, beneficiaries or
+ * autopay escrow, are added by the caller.
+ */
+export function getDisburseAccounts({
+  programId,
+  identifier,
+  signer,
+  creator,
+  recipient,
+  mint,
+  signerGovernanceAta,
+  creatorGovernanceAta,
+  recipientAta,
+  tokenProgram = TOKEN_2022_PROGRAM_ID,
+}: DisburseAccountsArgs) {
+  const { config, vault, vaultAta } = getPDAs(
+    programId,
+    identifier,
+    creator,
+    mint
+  );
+
+  return {
+    signer,
+    creator,
+    recipient,
+    config,
+    vault,
+    vaultAta,
+    signerGovernanceAta,
+    creatorGovernanceAta,
+    recipientAta,
+    mint,
+    governanceTokenMint: getGovernanceTokenMint(programId),
+    tokenProgram,
+    governanceTokenProgram: TOKEN_PROGRAM_ID,
+    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    systemProgram: anchor.web3.SystemProgram.programId,
+  };
+}
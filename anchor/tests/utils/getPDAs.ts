@@ -4,13 +4,31 @@ import type * as beet from "@metaplex-foundation/beet";
 import { PublicKey } from "@solana/web3.js";
 
 export const CONFIG_SEED = Buffer.from("config");
+export const PENDING_CONFIG_CHANGE_SEED = Buffer.from("pending_config_change");
 export const VAULT_SEED = Buffer.from("vault");
 export const VAULT_ATA_SEED = Buffer.from("vault_ata");
+export const SCHEDULE_SEED = Buffer.from("schedule");
+export const BENEFICIARIES_SEED = Buffer.from("beneficiaries");
+export const FEE_VAULT_SEED = Buffer.from("fee_vault");
+export const FEE_STATS_SEED = Buffer.from("fee_stats");
+export const NATIVE_FEE_STATS_SEED = Buffer.from("native_fee_stats");
+export const MINT_FEE_OVERRIDE_SEED = Buffer.from("mint_fee_override");
+export const AUTOPAY_ESCROW_SEED = Buffer.from("autopay_escrow");
 
 export interface ValhallaPDAs {
   config: PublicKey;
+  pendingConfigChange: PublicKey;
   vault: PublicKey;
   vaultAta: PublicKey;
+  schedule: PublicKey;
+  beneficiaries: PublicKey;
+  autopayEscrow: PublicKey;
+}
+
+export interface ValhallaFeePDAs {
+  feeVault: PublicKey;
+  feeStats: PublicKey;
+  mintFeeOverride: PublicKey;
 }
 
 export function getPDAs(
@@ -20,12 +38,20 @@ export function getPDAs(
   mint?: PublicKey
 ): ValhallaPDAs {
   const [config] = PublicKey.findProgramAddressSync([CONFIG_SEED], programId);
+  const [pendingConfigChange] = PublicKey.findProgramAddressSync(
+    [PENDING_CONFIG_CHANGE_SEED],
+    programId
+  );
 
   if (!identifier || !creator || !mint) {
     return {
       config,
+      pendingConfigChange,
       vault: new PublicKey(0),
       vaultAta: new PublicKey(0),
+      schedule: new PublicKey(0),
+      beneficiaries: new PublicKey(0),
+      autopayEscrow: new PublicKey(0),
     };
   }
 
@@ -44,9 +70,59 @@ export function getPDAs(
     programId
   );
 
+  const [schedule] = PublicKey.findProgramAddressSync(
+    [vault.toBuffer(), SCHEDULE_SEED],
+    programId
+  );
+
+  const [beneficiaries] = PublicKey.findProgramAddressSync(
+    [vault.toBuffer(), BENEFICIARIES_SEED],
+    programId
+  );
+
+  const [autopayEscrow] = PublicKey.findProgramAddressSync(
+    [vault.toBuffer(), AUTOPAY_ESCROW_SEED],
+    programId
+  );
+
   return {
     config,
+    pendingConfigChange,
     vault,
     vaultAta,
+    schedule,
+    beneficiaries,
+    autopayEscrow,
   };
 }
+
+export function getFeePDAs(
+  programId: PublicKey,
+  mint: PublicKey
+): ValhallaFeePDAs {
+  const [feeVault] = PublicKey.findProgramAddressSync(
+    [mint.toBuffer(), FEE_VAULT_SEED],
+    programId
+  );
+
+  const [feeStats] = PublicKey.findProgramAddressSync(
+    [mint.toBuffer(), FEE_STATS_SEED],
+    programId
+  );
+
+  const [mintFeeOverride] = PublicKey.findProgramAddressSync(
+    [mint.toBuffer(), MINT_FEE_OVERRIDE_SEED],
+    programId
+  );
+
+  return { feeVault, feeStats, mintFeeOverride };
+}
+
+export function getNativeFeeStatsPDA(programId: PublicKey): PublicKey {
+  const [nativeFeeStats] = PublicKey.findProgramAddressSync(
+    [NATIVE_FEE_STATS_SEED],
+    programId
+  );
+
+  return nativeFeeStats;
+}
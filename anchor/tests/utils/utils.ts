@@ -8,7 +8,7 @@ import {
 } from "@solana/spl-token";
 import { Commitment, Connection, Keypair, PublicKey } from "@solana/web3.js";
 
-import { AnchorProvider } from "@coral-xyz/anchor";
+import { AnchorProvider, IdlEvents } from "@coral-xyz/anchor";
 import { Valhalla } from "../../target/types/valhalla";
 import { airdrop } from "./airdrop";
 import { mintTransferFeeTokens } from "./mintTransferFeeTokens";
@@ -20,6 +20,25 @@ export enum Authority {
   Both,
 }
 
+export enum VestingMode {
+  Interval,
+  Continuous,
+  Scheduled,
+}
+
+export enum DepositMode {
+  ExtendSchedule,
+  IncreasePayouts,
+}
+
+export enum MintExtensionPolicy {
+  Denylist,
+  Allowlist,
+}
+
+export const defaultDeniedMintExtensions = (1 << 9) | (1 << 12) | (1 << 14);
+export const transferFeeConfigExtension = 1 << 1;
+
 export const decimals = 6;
 export const feeBasisPoints = 100;
 export const maxFee = 100 * 10 ** decimals;
@@ -56,6 +75,34 @@ export const confirm = async (
   return signature;
 };
 
+/**
+ * Returns the first event called `name` that the program emitted in a confirmed transaction.
+ */
+export const getEvent = async <N extends keyof IdlEvents<Valhalla>>(
+  program: anchor.Program<Valhalla>,
+  signature: string,
+  name: N
+): Promise<IdlEvents<Valhalla>[N]> => {
+  const transaction = await program.provider.connection.getTransaction(
+    signature,
+    { commitment: "confirmed", maxSupportedTransactionVersion: 0 }
+  );
+  const eventParser = new anchor.EventParser(
+    program.programId,
+    new anchor.BorshCoder(program.idl)
+  );
+
+  for (const event of eventParser.parseLogs(
+    transaction?.meta?.logMessages ?? []
+  )) {
+    if (event.name === name) {
+      return event.data as IdlEvents<Valhalla>[N];
+    }
+  }
+
+  throw new Error(`${String(name)} was not emitted`);
+};
+
 export const getName = (name: string) => {
   const nameArg: number[] = [];
   const name_ = anchor.utils.bytes.utf8.encode(name);
@@ -76,6 +123,33 @@ export const getAuthority = (
 ) =>
   program.coder.types.decode("Authority", new anchor.BN(authority).toBuffer());
 
+export const getVestingMode = (
+  vestingMode: VestingMode,
+  program: anchor.Program<Valhalla>
+) =>
+  program.coder.types.decode(
+    "VestingMode",
+    new anchor.BN(vestingMode).toBuffer()
+  );
+
+export const getDepositMode = (
+  depositMode: DepositMode,
+  program: anchor.Program<Valhalla>
+) =>
+  program.coder.types.decode(
+    "DepositMode",
+    new anchor.BN(depositMode).toBuffer()
+  );
+
+export const getMintExtensionPolicy = (
+  mintExtensionPolicy: MintExtensionPolicy,
+  program: anchor.Program<Valhalla>
+) =>
+  program.coder.types.decode(
+    "MintExtensionPolicy",
+    new anchor.BN(mintExtensionPolicy).toBuffer()
+  );
+
 export const CONFIG_SEED = Buffer.from("config");
 export const VAULT_SEED = Buffer.from("vault");
 export const VAULT_ATA_SEED = Buffer.from("vault_ata");
@@ -3,6 +3,7 @@ import * as anchor from "@coral-xyz/anchor";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   Account,
+  NATIVE_MINT,
   TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
   getAccount,
@@ -11,11 +12,23 @@ import {
 } from "@solana/spl-token";
 import {
   Authority,
+  DepositMode,
+  MintExtensionPolicy,
+  VestingMode,
   confirm,
+  decimals,
+  defaultDeniedMintExtensions,
+  feeBasisPoints,
   getAuthority,
+  getDepositMode,
+  getEvent,
+  getMintExtensionPolicy,
   getName,
+  getVestingMode,
+  maxFee,
   setupTestAccounts,
   sleep,
+  transferFeeConfigExtension,
 } from "./utils/utils";
 import {
   Keypair,
@@ -28,7 +41,9 @@ import { MPL_TOKEN_METADATA_PROGRAM_ID } from "@metaplex-foundation/mpl-token-me
 import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 import { Valhalla } from "../target/types/valhalla";
 import { airdrop } from "./utils/airdrop";
-import { getPDAs } from "./utils/getPDAs";
+import { getCreateAccounts, getDisburseAccounts } from "./utils/getAccounts";
+import { getFeePDAs, getNativeFeeStatsPDA, getPDAs } from "./utils/getPDAs";
+import { mintTransferFeeTokens } from "./utils/mintTransferFeeTokens";
 import { randomBytes } from "crypto";
 
 describe("⚡️ Valhalla", () => {
@@ -52,6 +67,9 @@ describe("⚡️ Valhalla", () => {
   let creatorGovernanceAta: Account;
   let userRewardAta: Account;
   let governanceTokenMint: PublicKey;
+  let feeVault: PublicKey;
+  let feeStats: PublicKey;
+  let mintFeeOverride: PublicKey;
   let metadata;
 
   beforeAll(async () => {
@@ -78,8 +96,41 @@ describe("⚡️ Valhalla", () => {
         randomUser,
         daoTreasury
       );
+
+    ({ feeVault, feeStats, mintFeeOverride } = getFeePDAs(
+      program.programId,
+      mint
+    ));
   }, 30000);
 
+  const createAccounts = (vaultIdentifier: anchor.BN) =>
+    getCreateAccounts({
+      programId: program.programId,
+      identifier: vaultIdentifier,
+      creator: creator.publicKey,
+      recipient: recipient.publicKey,
+      mint,
+      creatorAta: creatorTokenAccount.address,
+      creatorGovernanceAta: creatorGovernanceAta.address,
+    });
+
+  const disburseAccounts = (
+    vaultIdentifier: anchor.BN,
+    signer: PublicKey,
+    signerGovernanceAta: PublicKey
+  ) =>
+    getDisburseAccounts({
+      programId: program.programId,
+      identifier: vaultIdentifier,
+      signer,
+      creator: creator.publicKey,
+      recipient: recipient.publicKey,
+      mint,
+      signerGovernanceAta,
+      creatorGovernanceAta: creatorGovernanceAta.address,
+      recipientAta: recipientTokenAccount.address,
+    });
+
   describe("Create Config", () => {
     it("should fail if the token fee basis points are greater than 10000", async () => {
       try {
@@ -93,7 +144,8 @@ describe("⚡️ Valhalla", () => {
             new anchor.BN(0.025 * LAMPORTS_PER_SOL),
             new anchor.BN(5),
             new anchor.BN(10001),
-            new anchor.BN(0.01 * LAMPORTS_PER_SOL)
+            new anchor.BN(0.01 * LAMPORTS_PER_SOL),
+            new anchor.BN(0)
           )
           .accounts({
             admin: payer.publicKey,
@@ -140,7 +192,8 @@ describe("⚡️ Valhalla", () => {
             devFee,
             autopayMultiplier,
             tokenFeeBasisPoints,
-            governanceTokenAmount
+            governanceTokenAmount,
+            new anchor.BN(0)
           )
           .accounts({
             admin: payer.publicKey,
@@ -222,7 +275,8 @@ describe("⚡️ Valhalla", () => {
             new anchor.BN(0.025 * LAMPORTS_PER_SOL),
             new anchor.BN(5),
             new anchor.BN(10),
-            new anchor.BN(10 * LAMPORTS_PER_SOL)
+            new anchor.BN(10 * LAMPORTS_PER_SOL),
+            new anchor.BN(0)
           )
           .accounts({
             admin: payer.publicKey,
@@ -337,7 +391,7 @@ describe("⚡️ Valhalla", () => {
       const newAdmin = Keypair.generate();
 
       let tx = await program.methods
-        .updateAdmin()
+        .proposeAdmin()
         .accounts({
           admin: payer.publicKey,
           newAdmin: newAdmin.publicKey,
@@ -350,13 +404,43 @@ describe("⚡️ Valhalla", () => {
 
       let configAccount = await program.account.config.fetch(config);
       expect(configAccount.admin.toString()).toStrictEqual(
+        payer.publicKey.toString()
+      );
+      expect(configAccount.pendingAdmin.toString()).toStrictEqual(
         newAdmin.publicKey.toString()
       );
 
       await airdrop(provider.connection, newAdmin.publicKey);
 
       tx = await program.methods
-        .updateAdmin()
+        .acceptAdmin()
+        .accounts({
+          newAdmin: newAdmin.publicKey,
+          config,
+        })
+        .signers([newAdmin])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      configAccount = await program.account.config.fetch(config);
+      expect(configAccount.admin.toString()).toStrictEqual(
+        newAdmin.publicKey.toString()
+      );
+      expect(configAccount.pendingAdmin.toString()).toStrictEqual(
+        PublicKey.default.toString()
+      );
+
+      const configUpdated = await getEvent(program, tx, "ConfigUpdated");
+      expect(configUpdated.admin.toString()).toStrictEqual(
+        newAdmin.publicKey.toString()
+      );
+      expect(configUpdated.pendingAdmin.toString()).toStrictEqual(
+        PublicKey.default.toString()
+      );
+
+      tx = await program.methods
+        .proposeAdmin()
         .accounts({
           admin: newAdmin.publicKey,
           newAdmin: payer.publicKey,
@@ -367,21 +451,32 @@ describe("⚡️ Valhalla", () => {
 
       await confirm(provider.connection, tx);
 
+      tx = await program.methods
+        .acceptAdmin()
+        .accounts({
+          newAdmin: payer.publicKey,
+          config,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
       configAccount = await program.account.config.fetch(config);
       expect(configAccount.admin.toString()).toStrictEqual(
         payer.publicKey.toString()
       );
     });
 
-    it("should update the dao treasury", async () => {
+    it("should not let anyone but the proposed admin accept", async () => {
       const { config } = await getPDAs(program.programId);
-      const newDaoTreasury = Keypair.generate();
+      const newAdmin = Keypair.generate();
 
-      let tx = await program.methods
-        .updateDaoTreasury()
+      const tx = await program.methods
+        .proposeAdmin()
         .accounts({
           admin: payer.publicKey,
-          newDaoTreasury: newDaoTreasury.publicKey,
+          newAdmin: newAdmin.publicKey,
           config,
         })
         .signers([payer])
@@ -389,92 +484,262 @@ describe("⚡️ Valhalla", () => {
 
       await confirm(provider.connection, tx);
 
-      let configAccount = await program.account.config.fetch(config);
-      expect(configAccount.daoTreasury.toString()).toStrictEqual(
-        newDaoTreasury.publicKey.toString()
-      );
+      try {
+        const tx = await program.methods
+          .acceptAdmin()
+          .accounts({
+            newAdmin: randomUser.publicKey,
+            config,
+          })
+          .signers([randomUser])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("Unauthorized");
+        expect(e.error.errorCode.number).toStrictEqual(6001);
+      }
+    });
+
+    const executeConfigChange = async (configChange) => {
+      const { config, pendingConfigChange } = await getPDAs(program.programId);
+
+      let tx = await program.methods
+        .queueConfigChange(configChange)
+        .accounts({
+          admin: payer.publicKey,
+          config,
+          pendingConfigChange,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
 
-      await airdrop(provider.connection, newDaoTreasury.publicKey);
+      await confirm(provider.connection, tx);
 
       tx = await program.methods
-        .updateDaoTreasury()
+        .executeConfigChange()
         .accounts({
           admin: payer.publicKey,
-          newDaoTreasury: payer.publicKey,
           config,
+          pendingConfigChange,
         })
         .signers([payer])
         .rpc();
 
       await confirm(provider.connection, tx);
 
-      configAccount = await program.account.config.fetch(config);
+      const configUpdated = await getEvent(program, tx, "ConfigUpdated");
+      const configAccount = await program.account.config.fetch(config);
+
+      expect(configUpdated.admin.toString()).toStrictEqual(
+        configAccount.admin.toString()
+      );
+      expect(configUpdated.daoTreasury.toString()).toStrictEqual(
+        configAccount.daoTreasury.toString()
+      );
+      expect(configUpdated.devFee.toString()).toStrictEqual(
+        configAccount.devFee.toString()
+      );
+      expect(configUpdated.tokenFeeBasisPoints.toString()).toStrictEqual(
+        configAccount.tokenFeeBasisPoints.toString()
+      );
+      expect(configUpdated.governanceTokenAmount.toString()).toStrictEqual(
+        configAccount.governanceTokenAmount.toString()
+      );
+      expect(configUpdated.configChangeDelay.toString()).toStrictEqual(
+        configAccount.configChangeDelay.toString()
+      );
+      expect(configUpdated.crankRewardLamports.toString()).toStrictEqual(
+        configAccount.crankRewardLamports.toString()
+      );
+      expect(
+        configUpdated.crankRewardGovernanceTokenAmount.toString()
+      ).toStrictEqual(
+        configAccount.crankRewardGovernanceTokenAmount.toString()
+      );
+
+      return configAccount;
+    };
+
+    it("should update the dao treasury", async () => {
+      const newDaoTreasury = Keypair.generate();
+
+      let configAccount = await executeConfigChange({
+        daoTreasury: [newDaoTreasury.publicKey],
+      });
       expect(configAccount.daoTreasury.toString()).toStrictEqual(
-        payer.publicKey.toString()
+        newDaoTreasury.publicKey.toString()
+      );
+
+      configAccount = await executeConfigChange({
+        daoTreasury: [daoTreasury.publicKey],
+      });
+      expect(configAccount.daoTreasury.toString()).toStrictEqual(
+        daoTreasury.publicKey.toString()
       );
     });
 
     it("should update the dev fee", async () => {
-      const { config } = await getPDAs(program.programId);
       const newDevFee = new anchor.BN(0.05 * LAMPORTS_PER_SOL);
 
-      const tx = await program.methods
-        .updateDevFee(newDevFee)
+      const configAccount = await executeConfigChange({ devFee: [newDevFee] });
+      expect(configAccount.devFee.toString()).toStrictEqual(
+        newDevFee.toString()
+      );
+    });
+
+    it("should update the governance token amount", async () => {
+      const newGovernanceTokenAmount = new anchor.BN(20 * LAMPORTS_PER_SOL);
+
+      const configAccount = await executeConfigChange({
+        governanceTokenAmount: [newGovernanceTokenAmount],
+      });
+      expect(configAccount.governanceTokenAmount.toString()).toStrictEqual(
+        newGovernanceTokenAmount.toString()
+      );
+    });
+
+    it("should update the token fee basis points", async () => {
+      const newTokenFeeBasisPoints = new anchor.BN(100);
+
+      const configAccount = await executeConfigChange({
+        tokenFeeBasisPoints: [newTokenFeeBasisPoints],
+      });
+      expect(configAccount.tokenFeeBasisPoints.toString()).toStrictEqual(
+        newTokenFeeBasisPoints.toString()
+      );
+    });
+
+    it("should update the crank rewards", async () => {
+      const crankRewardLamports = new anchor.BN(5000);
+      const crankRewardGovernanceTokenAmount = new anchor.BN(LAMPORTS_PER_SOL);
+
+      let configAccount = await executeConfigChange({
+        crankRewardLamports: [crankRewardLamports],
+      });
+      expect(configAccount.crankRewardLamports.toString()).toStrictEqual(
+        crankRewardLamports.toString()
+      );
+
+      configAccount = await executeConfigChange({
+        crankRewardGovernanceTokenAmount: [crankRewardGovernanceTokenAmount],
+      });
+      expect(
+        configAccount.crankRewardGovernanceTokenAmount.toString()
+      ).toStrictEqual(crankRewardGovernanceTokenAmount.toString());
+    });
+
+    it("should not execute a config change before the delay has passed", async () => {
+      const { config, pendingConfigChange } = await getPDAs(program.programId);
+      const configChangeDelay = new anchor.BN(2);
+
+      let configAccount = await executeConfigChange({
+        configChangeDelay: [configChangeDelay],
+      });
+      expect(configAccount.configChangeDelay.toString()).toStrictEqual(
+        configChangeDelay.toString()
+      );
+
+      let tx = await program.methods
+        .queueConfigChange({ devFee: [new anchor.BN(LAMPORTS_PER_SOL)] })
         .accounts({
           admin: payer.publicKey,
           config,
+          pendingConfigChange,
+          systemProgram: anchor.web3.SystemProgram.programId,
         })
         .signers([payer])
         .rpc();
 
       await confirm(provider.connection, tx);
 
-      const configAccount = await program.account.config.fetch(config);
-      expect(configAccount.devFee.toString()).toStrictEqual(
-        newDevFee.toString()
-      );
-    });
+      try {
+        const tx = await program.methods
+          .executeConfigChange()
+          .accounts({
+            admin: payer.publicKey,
+            config,
+            pendingConfigChange,
+          })
+          .signers([payer])
+          .rpc();
 
-    it("should update the governance token amount", async () => {
-      const { config } = await getPDAs(program.programId);
-      const newGovernanceTokenAmount = new anchor.BN(20 * LAMPORTS_PER_SOL);
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("ConfigChangeLocked");
+        expect(e.error.errorCode.number).toStrictEqual(6019);
+      }
 
-      const tx = await program.methods
-        .updateGovernanceTokenAmount(newGovernanceTokenAmount)
+      tx = await program.methods
+        .cancelConfigChange()
         .accounts({
           admin: payer.publicKey,
           config,
+          pendingConfigChange,
         })
         .signers([payer])
         .rpc();
 
       await confirm(provider.connection, tx);
 
-      const configAccount = await program.account.config.fetch(config);
-      expect(configAccount.governanceTokenAmount.toString()).toStrictEqual(
-        newGovernanceTokenAmount.toString()
-      );
-    });
+      const pendingConfigChangeInfo =
+        await provider.connection.getAccountInfo(pendingConfigChange);
+      expect(pendingConfigChangeInfo).toBeNull();
 
-    it("should update the token fee basis points", async () => {
-      const { config } = await getPDAs(program.programId);
-      const newTokenFeeBasisPoints = new anchor.BN(100);
+      // Restore the delay so the remaining tests can update the config.
+      tx = await program.methods
+        .queueConfigChange({ configChangeDelay: [new anchor.BN(0)] })
+        .accounts({
+          admin: payer.publicKey,
+          config,
+          pendingConfigChange,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
 
-      const tx = await program.methods
-        .updateTokenFeeBasisPoints(newTokenFeeBasisPoints)
+      await confirm(provider.connection, tx);
+      await sleep(3000);
+
+      tx = await program.methods
+        .executeConfigChange()
         .accounts({
           admin: payer.publicKey,
           config,
+          pendingConfigChange,
         })
         .signers([payer])
         .rpc();
 
       await confirm(provider.connection, tx);
 
-      const configAccount = await program.account.config.fetch(config);
-      expect(configAccount.tokenFeeBasisPoints.toString()).toStrictEqual(
-        newTokenFeeBasisPoints.toString()
-      );
+      configAccount = await program.account.config.fetch(config);
+      expect(configAccount.configChangeDelay.toNumber()).toStrictEqual(0);
+    });
+
+    it("should not migrate a config that already has the current layout", async () => {
+      const { config } = await getPDAs(program.programId);
+
+      try {
+        const tx = await program.methods
+          .migrateConfig(new anchor.BN(0))
+          .accounts({
+            admin: payer.publicKey,
+            config,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([payer])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("AlreadyMigrated");
+        expect(e.error.errorCode.number).toStrictEqual(6023);
+      }
     });
   });
 
@@ -483,12 +748,20 @@ describe("⚡️ Valhalla", () => {
       identifier = new anchor.BN(randomBytes(8));
       const name = getName("Vault");
       const autopay = false;
+      const allowPublicDeposits = false;
       const amountToBeVested = new anchor.BN(100);
       const totalVestingDuration = new anchor.BN(10);
       const startDate = new anchor.BN(new Date().getTime() / 1000);
       const payoutInterval = new anchor.BN(1);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
       const cancelAuthority = await getAuthority(Authority.Neither, program);
-      const { config, vault, vaultAta } = await getPDAs(
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { vault, vaultAta } = await getPDAs(
         program.programId,
         identifier,
         creator.publicKey,
@@ -507,18 +780,10 @@ describe("⚡️ Valhalla", () => {
         ASSOCIATED_TOKEN_PROGRAM_ID
       );
 
-      const devTreasuryBalanceBefore = await provider.connection.getBalance(
-        payer.publicKey
-      );
-
-      const treasuryAtaBefore = await getAccount(
-        provider.connection,
-        treasuryTokenAccount.address,
-        undefined,
-        TOKEN_2022_PROGRAM_ID
+      const feeStatsInfoBefore = await provider.connection.getAccountInfo(
+        feeStats
       );
-
-      expect(treasuryAtaBefore.amount).toStrictEqual(0n);
+      expect(feeStatsInfoBefore).toBeNull();
 
       const tx = await program.methods
         .create(
@@ -528,27 +793,15 @@ describe("⚡️ Valhalla", () => {
           totalVestingDuration,
           startDate,
           payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
           cancelAuthority,
-          autopay
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
         )
-        .accounts({
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          devTreasury: payer.publicKey,
-          daoTreasury: daoTreasury.publicKey,
-          config,
-          vault,
-          vaultAta,
-          daoTreasuryAta: treasuryTokenAccount.address,
-          creatorAta: creatorTokenAccount.address,
-          creatorGovernanceAta: creatorGovernanceAta.address,
-          mint,
-          governanceTokenMint,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          governanceTokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
+        .accounts(createAccounts(identifier))
         .signers([creator])
         .rpc();
 
@@ -582,22 +835,51 @@ describe("⚡️ Valhalla", () => {
         cancelAuthority.toString()
       );
 
-      const devTreasuryBalanceAfter = await provider.connection.getBalance(
-        payer.publicKey
+      const vaultAtaAccount = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
       );
 
-      const treasuryAtaAfter = await getAccount(
+      expect(vaultAccount.initialDepositAmount.toString()).toStrictEqual(
+        vaultAtaAccount.amount.toString()
+      );
+
+      const feeVaultAccount = await getAccount(
         provider.connection,
-        treasuryTokenAccount.address,
+        feeVault,
         undefined,
         TOKEN_2022_PROGRAM_ID
       );
+      const feeStatsAccount = await program.account.feeStats.fetch(feeStats);
 
-      expect(devTreasuryBalanceAfter).toBeGreaterThan(devTreasuryBalanceBefore);
+      expect(Number(feeVaultAccount.amount)).toBeGreaterThan(0);
+      expect(feeStatsAccount.mint.toString()).toStrictEqual(mint.toString());
+      expect(feeStatsAccount.totalTokenFees.toString()).toStrictEqual(
+        feeVaultAccount.amount.toString()
+      );
 
-      expect(Number(treasuryAtaAfter.amount)).toBeGreaterThan(
-        Number(treasuryAtaBefore.amount)
+      const vaultCreated = await getEvent(program, tx, "VaultCreated");
+      expect(vaultCreated.vault.toString()).toStrictEqual(vault.toString());
+      expect(vaultCreated.creator.toString()).toStrictEqual(
+        creator.publicKey.toString()
+      );
+      expect(vaultCreated.recipient.toString()).toStrictEqual(
+        recipient.publicKey.toString()
+      );
+      expect(vaultCreated.mint.toString()).toStrictEqual(mint.toString());
+      expect(vaultCreated.depositAmount.toString()).toStrictEqual(
+        vaultAccount.initialDepositAmount.toString()
+      );
+      expect(vaultCreated.tokenFeeAmount.toNumber()).toBeGreaterThanOrEqual(
+        feeStatsAccount.totalTokenFees.toNumber()
+      );
+      expect(vaultCreated.solFeeAmount.toString()).toStrictEqual(
+        feeStatsAccount.totalSolFees.toString()
       );
+      expect(vaultCreated.autopayAmount.toNumber()).toStrictEqual(0);
+      expect(feeStatsAccount.totalSolFees.toNumber()).toBeGreaterThan(0);
     });
 
     it("should not allow cancellation", async () => {
@@ -617,6 +899,7 @@ describe("⚡️ Valhalla", () => {
             vault,
             vaultAta,
             creatorAta: creatorTokenAccount.address,
+            recipientAta: recipientTokenAccount.address,
             mint,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -638,7 +921,7 @@ describe("⚡️ Valhalla", () => {
 
     it("should not disburse if vault is locked", async () => {
       try {
-        const { config, vault, vaultAta } = await getPDAs(
+        const { vault } = await getPDAs(
           program.programId,
           identifier,
           creator.publicKey,
@@ -647,24 +930,13 @@ describe("⚡️ Valhalla", () => {
 
         const tx = await program.methods
           .disburse()
-          .accounts({
-            signer: creator.publicKey,
-            creator: creator.publicKey,
-            recipient: recipient.publicKey,
-            devTreasury: payer.publicKey,
-            config,
-            vault,
-            vaultAta,
-            signerGovernanceAta: creatorGovernanceAta.address,
-            creatorGovernanceAta: creatorGovernanceAta.address,
-            recipientAta: recipientTokenAccount.address,
-            mint,
-            governanceTokenMint,
-            tokenProgram: TOKEN_2022_PROGRAM_ID,
-            governanceTokenProgram: TOKEN_PROGRAM_ID,
-            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-            systemProgram: anchor.web3.SystemProgram.programId,
-          })
+          .accounts(
+            disburseAccounts(
+              identifier,
+              creator.publicKey,
+              creatorGovernanceAta.address
+            )
+          )
           .signers([creator])
           .rpc();
 
@@ -677,7 +949,7 @@ describe("⚡️ Valhalla", () => {
     });
 
     it("should let any user disburse", async () => {
-      const { config, vault, vaultAta } = await getPDAs(
+      const { vault } = await getPDAs(
         program.programId,
         identifier,
         creator.publicKey,
@@ -687,24 +959,13 @@ describe("⚡️ Valhalla", () => {
       await sleep(2000);
       const tx = await program.methods
         .disburse()
-        .accounts({
-          signer: randomUser.publicKey,
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          devTreasury: payer.publicKey,
-          config,
-          vault,
-          vaultAta,
-          signerGovernanceAta: userRewardAta.address,
-          creatorGovernanceAta: creatorGovernanceAta.address,
-          recipientAta: recipientTokenAccount.address,
-          mint,
-          governanceTokenMint,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          governanceTokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
+        .accounts(
+          disburseAccounts(
+            identifier,
+            randomUser.publicKey,
+            userRewardAta.address
+          )
+        )
         // tests the disburse function with random user as signer
         .signers([randomUser])
         .rpc();
@@ -722,6 +983,24 @@ describe("⚡️ Valhalla", () => {
       expect(vaultAccount.numberOfPaymentsMade.toNumber()).toBeGreaterThan(0);
 
       expect(Number(recipientAccount.amount)).toBeGreaterThan(0);
+
+      const vaultDisbursed = await getEvent(program, tx, "VaultDisbursed");
+      expect(vaultDisbursed.vault.toString()).toStrictEqual(vault.toString());
+      expect(vaultDisbursed.recipient.toString()).toStrictEqual(
+        recipient.publicKey.toString()
+      );
+      expect(vaultDisbursed.signer.toString()).toStrictEqual(
+        randomUser.publicKey.toString()
+      );
+      expect(vaultDisbursed.amount.toString()).toStrictEqual(
+        vaultAccount.totalWithdrawn.toString()
+      );
+      expect(vaultDisbursed.totalWithdrawn.toString()).toStrictEqual(
+        vaultAccount.totalWithdrawn.toString()
+      );
+      expect(vaultDisbursed.numberOfPaymentsMade.toString()).toStrictEqual(
+        vaultAccount.numberOfPaymentsMade.toString()
+      );
     });
   });
 
@@ -730,12 +1009,20 @@ describe("⚡️ Valhalla", () => {
       identifier = new anchor.BN(randomBytes(8));
       const name = getName("Vault");
       const autopay = true;
+      const allowPublicDeposits = false;
       const amountToBeVested = new anchor.BN(100);
       const totalVestingDuration = new anchor.BN(10);
       const startDate = new anchor.BN(new Date().getTime() / 1000);
       const payoutInterval = new anchor.BN(5);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
       const cancelAuthority = await getAuthority(Authority.Recipient, program);
-      const { config, vault, vaultAta } = await getPDAs(
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { autopayEscrow } = await getPDAs(
         program.programId,
         identifier,
         creator.publicKey,
@@ -762,26 +1049,17 @@ describe("⚡️ Valhalla", () => {
           totalVestingDuration,
           startDate,
           payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
           cancelAuthority,
-          autopay
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
         )
         .accounts({
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          devTreasury: payer.publicKey,
-          daoTreasury: daoTreasury.publicKey,
-          config,
-          vault,
-          vaultAta,
-          daoTreasuryAta: treasuryTokenAccount.address,
-          creatorAta: creatorTokenAccount.address,
-          creatorGovernanceAta: creatorGovernanceAta.address,
-          mint,
-          governanceTokenMint,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          governanceTokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
+          ...createAccounts(identifier),
+          autopayEscrow,
         })
         .signers([creator])
         .rpc();
@@ -791,7 +1069,7 @@ describe("⚡️ Valhalla", () => {
 
     it("should not allow the creator to cancel", async () => {
       try {
-        const { vault, vaultAta } = await getPDAs(
+        const { vault, vaultAta, autopayEscrow } = await getPDAs(
           program.programId,
           identifier,
           creator.publicKey,
@@ -806,7 +1084,9 @@ describe("⚡️ Valhalla", () => {
             recipient: recipient.publicKey,
             vault,
             vaultAta,
+            autopayEscrow,
             creatorAta: creatorTokenAccount.address,
+            recipientAta: recipientTokenAccount.address,
             mint,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -826,7 +1106,7 @@ describe("⚡️ Valhalla", () => {
     });
 
     it("should allow the recipient to cancel", async () => {
-      const { vault, vaultAta } = await getPDAs(
+      const { vault, vaultAta, autopayEscrow } = await getPDAs(
         program.programId,
         identifier,
         creator.publicKey,
@@ -841,6 +1121,12 @@ describe("⚡️ Valhalla", () => {
         undefined,
         TOKEN_2022_PROGRAM_ID
       );
+      const vaultAtaBefore = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
 
       const tx = await program.methods
         .cancel()
@@ -850,7 +1136,9 @@ describe("⚡️ Valhalla", () => {
           recipient: recipient.publicKey,
           vault,
           vaultAta,
+          autopayEscrow,
           creatorAta: creatorTokenAccount.address,
+          recipientAta: recipientTokenAccount.address,
           mint,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -861,6 +1149,17 @@ describe("⚡️ Valhalla", () => {
 
       await confirm(provider.connection, tx);
 
+      const vaultCancelled = await getEvent(program, tx, "VaultCancelled");
+      expect(vaultCancelled.vault.toString()).toStrictEqual(vault.toString());
+      expect(vaultCancelled.signer.toString()).toStrictEqual(
+        recipient.publicKey.toString()
+      );
+      expect(
+        vaultCancelled.recipientAmount
+          .add(vaultCancelled.creatorAmount)
+          .toString()
+      ).toStrictEqual(vaultAtaBefore.amount.toString());
+
       try {
         await getAccount(
           provider.connection,
@@ -880,16 +1179,18 @@ describe("⚡️ Valhalla", () => {
       identifier = new anchor.BN(randomBytes(8));
       const name = getName("Vault");
       const autopay = false;
+      const allowPublicDeposits = false;
       const amountToBeVested = new anchor.BN(100);
       const totalVestingDuration = new anchor.BN(10);
       const startDate = new anchor.BN(new Date().getTime() / 1000);
       const payoutInterval = new anchor.BN(5);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
       const cancelAuthority = await getAuthority(Authority.Creator, program);
-      const { config, vault, vaultAta } = await getPDAs(
-        program.programId,
-        identifier,
-        creator.publicKey,
-        mint
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
       );
 
       creatorGovernanceAta = await getOrCreateAssociatedTokenAccount(
@@ -912,27 +1213,15 @@ describe("⚡️ Valhalla", () => {
           totalVestingDuration,
           startDate,
           payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
           cancelAuthority,
-          autopay
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
         )
-        .accounts({
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          devTreasury: payer.publicKey,
-          daoTreasury: daoTreasury.publicKey,
-          config,
-          vault,
-          vaultAta,
-          daoTreasuryAta: treasuryTokenAccount.address,
-          creatorAta: creatorTokenAccount.address,
-          creatorGovernanceAta: creatorGovernanceAta.address,
-          mint,
-          governanceTokenMint,
-          governanceTokenProgram: TOKEN_PROGRAM_ID,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
+        .accounts(createAccounts(identifier))
         .signers([creator])
         .rpc();
 
@@ -957,6 +1246,7 @@ describe("⚡️ Valhalla", () => {
             vault,
             vaultAta,
             creatorAta: creatorTokenAccount.address,
+            recipientAta: recipientTokenAccount.address,
             mint,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -1001,6 +1291,7 @@ describe("⚡️ Valhalla", () => {
           vault,
           vaultAta,
           creatorAta: creatorTokenAccount.address,
+          recipientAta: recipientTokenAccount.address,
           mint,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -1025,17 +1316,136 @@ describe("⚡️ Valhalla", () => {
     });
   });
 
+  describe("Vault w/ Partial Vesting Cancel", () => {
+    // Token-2022 rounds the transfer fee up and caps it at the maximum fee.
+    const afterTransferFee = (amount: bigint) => {
+      const fee =
+        (amount * BigInt(feeBasisPoints) + BigInt(9_999)) / BigInt(10_000);
+
+      return amount - (fee > BigInt(maxFee) ? BigInt(maxFee) : fee);
+    };
+
+    it("should pay the vested amount to the recipient and the rest to the creator", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const { vault, vaultAta } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      // Three payouts at 10, 20 and 30 seconds.
+      let tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(30),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(10),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Creator, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      // Cancel once the first payout has vested.
+      await sleep(12000);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+      const vaultAtaAccount = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const recipientBefore = await getAccount(
+        provider.connection,
+        recipientTokenAccount.address,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const creatorBefore = await getAccount(
+        provider.connection,
+        creatorTokenAccount.address,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      tx = await program.methods
+        .cancel()
+        .accounts({
+          signer: creator.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          vault,
+          vaultAta,
+          creatorAta: creatorTokenAccount.address,
+          recipientAta: recipientTokenAccount.address,
+          mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const recipientAfter = await getAccount(
+        provider.connection,
+        recipientTokenAccount.address,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const creatorAfter = await getAccount(
+        provider.connection,
+        creatorTokenAccount.address,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const vestedAmount = BigInt(
+        vaultAccount.initialDepositAmount.divn(3).toString()
+      );
+      const remainingAmount = vaultAtaAccount.amount - vestedAmount;
+
+      expect(recipientAfter.amount - recipientBefore.amount).toStrictEqual(
+        afterTransferFee(vestedAmount)
+      );
+      expect(creatorAfter.amount - creatorBefore.amount).toStrictEqual(
+        afterTransferFee(remainingAmount)
+      );
+    }, 30000);
+  });
+
   describe("Vault w/ Both Cancel Authority", () => {
     it("should create a vault with both update authorites", async () => {
       identifier = new anchor.BN(randomBytes(8));
       const name = getName("Vault");
       const autopay = true;
+      const allowPublicDeposits = false;
       const amountToBeVested = new anchor.BN(100);
       const totalVestingDuration = new anchor.BN(10);
       const startDate = new anchor.BN(new Date().getTime() / 1000);
       const payoutInterval = new anchor.BN(5);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
       const cancelAuthority = await getAuthority(Authority.Both, program);
-      const { config, vault, vaultAta } = await getPDAs(
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { autopayEscrow } = await getPDAs(
         program.programId,
         identifier,
         creator.publicKey,
@@ -1062,26 +1472,17 @@ describe("⚡️ Valhalla", () => {
           totalVestingDuration,
           startDate,
           payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
           cancelAuthority,
-          autopay
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
         )
         .accounts({
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          devTreasury: payer.publicKey,
-          daoTreasury: daoTreasury.publicKey,
-          config,
-          vault,
-          vaultAta,
-          daoTreasuryAta: treasuryTokenAccount.address,
-          creatorAta: creatorTokenAccount.address,
-          creatorGovernanceAta: creatorGovernanceAta.address,
-          mint,
-          governanceTokenMint,
-          governanceTokenProgram: TOKEN_PROGRAM_ID,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
+          ...createAccounts(identifier),
+          autopayEscrow,
         })
         .signers([creator])
         .rpc();
@@ -1090,7 +1491,7 @@ describe("⚡️ Valhalla", () => {
     });
 
     it("should allow the recipient to cancel", async () => {
-      const { vault, vaultAta } = await getPDAs(
+      const { vault, vaultAta, autopayEscrow } = await getPDAs(
         program.programId,
         identifier,
         creator.publicKey,
@@ -1114,7 +1515,9 @@ describe("⚡️ Valhalla", () => {
           recipient: recipient.publicKey,
           vault,
           vaultAta,
+          autopayEscrow,
           creatorAta: creatorTokenAccount.address,
+          recipientAta: recipientTokenAccount.address,
           mint,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -1142,16 +1545,18 @@ describe("⚡️ Valhalla", () => {
       identifier = new anchor.BN(randomBytes(8));
       const name = getName("Vault");
       const autopay = false;
+      const allowPublicDeposits = false;
       const amountToBeVested = new anchor.BN(100);
       const totalVestingDuration = new anchor.BN(10);
       const startDate = new anchor.BN(new Date().getTime() / 1000);
       const payoutInterval = new anchor.BN(5);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
       const cancelAuthority = await getAuthority(Authority.Both, program);
-      const { config, vault, vaultAta } = await getPDAs(
-        program.programId,
-        identifier,
-        creator.publicKey,
-        mint
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
       );
 
       creatorGovernanceAta = await getOrCreateAssociatedTokenAccount(
@@ -1174,27 +1579,15 @@ describe("⚡️ Valhalla", () => {
           totalVestingDuration,
           startDate,
           payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
           cancelAuthority,
-          autopay
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
         )
-        .accounts({
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          devTreasury: payer.publicKey,
-          daoTreasury: daoTreasury.publicKey,
-          config,
-          vault,
-          vaultAta,
-          daoTreasuryAta: treasuryTokenAccount.address,
-          creatorAta: creatorTokenAccount.address,
-          creatorGovernanceAta: creatorGovernanceAta.address,
-          mint,
-          governanceTokenMint,
-          governanceTokenProgram: TOKEN_PROGRAM_ID,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
+        .accounts(createAccounts(identifier))
         .signers([creator])
         .rpc();
 
@@ -1227,6 +1620,7 @@ describe("⚡️ Valhalla", () => {
           vault,
           vaultAta,
           creatorAta: creatorTokenAccount.address,
+          recipientAta: recipientTokenAccount.address,
           mint,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -1256,12 +1650,20 @@ describe("⚡️ Valhalla", () => {
       identifier = new anchor.BN(randomBytes(8));
       const name = getName("Vault");
       const autopay = true;
+      const allowPublicDeposits = false;
       const amountToBeVested = new anchor.BN(100);
       const totalVestingDuration = new anchor.BN(10);
       const startDate = new anchor.BN(new Date().getTime() / 1000);
       const payoutInterval = new anchor.BN(1);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
       const cancelAuthority = await getAuthority(Authority.Both, program);
-      const { config, vault, vaultAta } = await getPDAs(
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { vault, vaultAta, autopayEscrow } = await getPDAs(
         program.programId,
         identifier,
         creator.publicKey,
@@ -1288,26 +1690,17 @@ describe("⚡️ Valhalla", () => {
           totalVestingDuration,
           startDate,
           payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
           cancelAuthority,
-          autopay
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
         )
         .accounts({
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          devTreasury: payer.publicKey,
-          daoTreasury: daoTreasury.publicKey,
-          config,
-          vault,
-          vaultAta,
-          daoTreasuryAta: treasuryTokenAccount.address,
-          creatorAta: creatorTokenAccount.address,
-          creatorGovernanceAta: creatorGovernanceAta.address,
-          mint,
-          governanceTokenMint,
-          governanceTokenProgram: TOKEN_PROGRAM_ID,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
+          ...createAccounts(identifier),
+          autopayEscrow,
         })
         .signers([creator])
         .rpc();
@@ -1322,6 +1715,7 @@ describe("⚡️ Valhalla", () => {
             creator: creator.publicKey,
             vault,
             vaultAta,
+            autopayEscrow,
             mint,
             tokenProgram: TOKEN_2022_PROGRAM_ID,
           })
@@ -1340,12 +1734,20 @@ describe("⚡️ Valhalla", () => {
       identifier = new anchor.BN(randomBytes(8));
       const name = getName("Vault");
       const autopay = false;
+      const allowPublicDeposits = false;
       const amountToBeVested = new anchor.BN(100);
       const totalVestingDuration = new anchor.BN(1);
       const startDate = new anchor.BN(new Date().getTime() / 1000);
       const payoutInterval = new anchor.BN(1);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
       const cancelAuthority = await getAuthority(Authority.Both, program);
-      const { config, vault, vaultAta } = await getPDAs(
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { vault, vaultAta } = await getPDAs(
         program.programId,
         identifier,
         creator.publicKey,
@@ -1372,27 +1774,15 @@ describe("⚡️ Valhalla", () => {
           totalVestingDuration,
           startDate,
           payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
           cancelAuthority,
-          autopay
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
         )
-        .accounts({
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          devTreasury: payer.publicKey,
-          daoTreasury: daoTreasury.publicKey,
-          config,
-          vault,
-          vaultAta,
-          daoTreasuryAta: treasuryTokenAccount.address,
-          creatorAta: creatorTokenAccount.address,
-          creatorGovernanceAta: creatorGovernanceAta.address,
-          mint,
-          governanceTokenMint,
-          governanceTokenProgram: TOKEN_PROGRAM_ID,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
+        .accounts(createAccounts(identifier))
         .signers([creator])
         .rpc();
 
@@ -1401,24 +1791,13 @@ describe("⚡️ Valhalla", () => {
       await sleep(3000);
       tx = await program.methods
         .disburse()
-        .accounts({
-          signer: creator.publicKey,
-          creator: creator.publicKey,
-          recipient: recipient.publicKey,
-          devTreasury: payer.publicKey,
-          config,
-          vault,
-          vaultAta,
-          signerGovernanceAta: creatorGovernanceAta.address,
-          creatorGovernanceAta: creatorGovernanceAta.address,
-          recipientAta: recipientTokenAccount.address,
-          mint,
-          governanceTokenMint,
-          tokenProgram: TOKEN_2022_PROGRAM_ID,
-          governanceTokenProgram: TOKEN_PROGRAM_ID,
-          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-          systemProgram: anchor.web3.SystemProgram.programId,
-        })
+        .accounts(
+          disburseAccounts(
+            identifier,
+            creator.publicKey,
+            creatorGovernanceAta.address
+          )
+        )
         .signers([creator])
         .rpc();
 
@@ -1447,7 +1826,13 @@ describe("⚡️ Valhalla", () => {
 
       await confirm(provider.connection, tx);
 
-      try {
+      const vaultClosed = await getEvent(program, tx, "VaultClosed");
+      expect(vaultClosed.vault.toString()).toStrictEqual(vault.toString());
+      expect(vaultClosed.creator.toString()).toStrictEqual(
+        creator.publicKey.toString()
+      );
+
+      try {
         await getAccount(
           provider.connection,
           vaultAta,
@@ -1460,4 +1845,2987 @@ describe("⚡️ Valhalla", () => {
       }
     });
   });
+
+  describe("Vault w/ Cliff", () => {
+    it("should create a vault with a cliff", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const name = getName("Vault");
+      const autopay = false;
+      const allowPublicDeposits = false;
+      const amountToBeVested = new anchor.BN(100);
+      const totalVestingDuration = new anchor.BN(1000);
+      const startDate = new anchor.BN(new Date().getTime() / 1000);
+      const payoutInterval = new anchor.BN(100);
+      const cliffDate = startDate.add(new anchor.BN(500));
+      const cliffAmount = new anchor.BN(50);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
+      const cancelAuthority = await getAuthority(Authority.Neither, program);
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { vault } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      const tx = await program.methods
+        .create(
+          identifier,
+          name,
+          amountToBeVested,
+          totalVestingDuration,
+          startDate,
+          payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
+          cancelAuthority,
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+
+      expect(vaultAccount.cliffDate.toString()).toStrictEqual(
+        cliffDate.toString()
+      );
+      expect(vaultAccount.isCliffPaymentDisbursed).toStrictEqual(false);
+      expect(vaultAccount.totalNumberOfPayouts.toNumber()).toStrictEqual(5);
+    });
+
+    it("should not disburse before the cliff date", async () => {
+      try {
+        const { vault } = await getPDAs(
+          program.programId,
+          identifier,
+          creator.publicKey,
+          mint
+        );
+
+        const tx = await program.methods
+          .disburse()
+          .accounts(
+            disburseAccounts(
+              identifier,
+              creator.publicKey,
+              creatorGovernanceAta.address
+            )
+          )
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("Locked");
+        expect(e.error.errorCode.number).toStrictEqual(6000);
+        expect(e.error.errorMessage).toStrictEqual("The vault is locked!");
+      }
+    });
+  });
+
+  describe("Vault w/ Missed Intervals", () => {
+    it("should pay every missed interval in one disbursement", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const startDate = new anchor.BN(new Date().getTime() / 1000);
+      const payoutInterval = new anchor.BN(3);
+      const { vault, vaultAta } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      // Five payouts, one every 3 seconds.
+      let tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(15),
+          startDate,
+          payoutInterval,
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultBefore = await program.account.vault.fetch(vault);
+      const vaultAtaBefore = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      // Let several intervals pass without disbursing.
+      await sleep(10000);
+      tx = await program.methods
+        .disburse()
+        .accounts(
+          disburseAccounts(
+            identifier,
+            creator.publicKey,
+            creatorGovernanceAta.address
+          )
+        )
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAfter = await program.account.vault.fetch(vault);
+      const vaultAtaAfter = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const payoutsMade = vaultAfter.numberOfPaymentsMade.toNumber();
+
+      expect(payoutsMade).toBeGreaterThan(1);
+      expect(payoutsMade).toBeLessThan(
+        vaultAfter.totalNumberOfPayouts.toNumber()
+      );
+
+      // Every missed payout is paid at once...
+      expect(
+        (vaultAtaBefore.amount - vaultAtaAfter.amount).toString()
+      ).toStrictEqual(
+        vaultBefore.initialDepositAmount
+          .muln(payoutsMade)
+          .div(vaultBefore.totalNumberOfPayouts)
+          .toString()
+      );
+
+      // ...and the last payment timestamp advances by whole intervals.
+      expect(
+        vaultAfter.lastPaymentTimestamp
+          .sub(vaultBefore.lastPaymentTimestamp)
+          .toString()
+      ).toStrictEqual(payoutInterval.muln(payoutsMade).toString());
+    }, 30000);
+  });
+
+  describe("Vault w/ Continuous Vesting", () => {
+    it("should disburse everything vested so far", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const name = getName("Vault");
+      const autopay = false;
+      const allowPublicDeposits = false;
+      const amountToBeVested = new anchor.BN(100);
+      const totalVestingDuration = new anchor.BN(10);
+      const startDate = new anchor.BN(new Date().getTime() / 1000);
+      const payoutInterval = new anchor.BN(10);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Continuous, program);
+      const cancelAuthority = await getAuthority(Authority.Neither, program);
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { vault } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      let tx = await program.methods
+        .create(
+          identifier,
+          name,
+          amountToBeVested,
+          totalVestingDuration,
+          startDate,
+          payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
+          cancelAuthority,
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      await sleep(3000);
+      tx = await program.methods
+        .disburse()
+        .accounts(
+          disburseAccounts(
+            identifier,
+            creator.publicKey,
+            creatorGovernanceAta.address
+          )
+        )
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+
+      expect(vaultAccount.totalWithdrawn.toNumber()).toBeGreaterThan(0);
+      expect(vaultAccount.totalWithdrawn.toNumber()).toBeLessThan(
+        vaultAccount.initialDepositAmount.toNumber()
+      );
+      // No payout interval has elapsed yet, however often the vault is disbursed.
+      expect(vaultAccount.numberOfPaymentsMade.toNumber()).toStrictEqual(0);
+    });
+
+    it("should report the claimable amount", async () => {
+      const { vault, vaultAta } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      await sleep(1000);
+      const claimable = await program.methods
+        .getClaimable()
+        .accounts({ vault, vaultAta })
+        .view();
+
+      const vaultTokenAccount = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      expect(claimable.amount.toNumber()).toBeGreaterThan(0);
+      expect(claimable.remainingBalance.toString()).toStrictEqual(
+        vaultTokenAccount.amount.toString()
+      );
+    });
+  });
+
+  describe("Vault w/ Schedule", () => {
+    it("should disburse every tranche that has unlocked", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const name = getName("Vault");
+      const autopay = false;
+      const allowPublicDeposits = false;
+      const now = new anchor.BN(new Date().getTime() / 1000);
+      const tranches = [
+        { timestamp: now, amount: new anchor.BN(10) },
+        { timestamp: now.add(new anchor.BN(1000)), amount: new anchor.BN(90) },
+      ];
+      const cancelAuthority = await getAuthority(Authority.Neither, program);
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { vault, schedule } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      let tx = await program.methods
+        .createWithSchedule(
+          identifier,
+          name,
+          tranches,
+          cancelAuthority,
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
+        )
+        .accounts({
+          ...createAccounts(identifier),
+          schedule,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      await sleep(1000);
+      tx = await program.methods
+        .disburse()
+        .accounts({
+          ...disburseAccounts(
+            identifier,
+            creator.publicKey,
+            creatorGovernanceAta.address
+          ),
+          schedule,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+
+      expect(vaultAccount.totalWithdrawn.toString()).toStrictEqual(
+        new anchor.BN(10 * 10 ** decimals).toString()
+      );
+      expect(vaultAccount.numberOfPaymentsMade.toNumber()).toStrictEqual(1);
+    });
+
+    it("should close the schedule when the vault is cancelled", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const now = new anchor.BN(new Date().getTime() / 1000);
+      const tranches = [
+        { timestamp: now.add(new anchor.BN(1000)), amount: new anchor.BN(100) },
+      ];
+      const { vault, vaultAta, schedule } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      let tx = await program.methods
+        .createWithSchedule(
+          identifier,
+          getName("Vault"),
+          tranches,
+          await getAuthority(Authority.Creator, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts({
+          ...createAccounts(identifier),
+          schedule,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const cancelAccounts = {
+        signer: creator.publicKey,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        vault,
+        vaultAta,
+        creatorAta: creatorTokenAccount.address,
+        recipientAta: recipientTokenAccount.address,
+        mint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      };
+
+      try {
+        tx = await program.methods
+          .cancel()
+          .accounts(cancelAccounts)
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("InvalidSchedule");
+        expect(e.error.errorCode.number).toStrictEqual(6009);
+      }
+
+      tx = await program.methods
+        .cancel()
+        .accounts({ ...cancelAccounts, schedule })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      expect(await provider.connection.getAccountInfo(schedule)).toBeNull();
+    });
+
+    it("should not create a schedule that starts in the past", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const now = new anchor.BN(new Date().getTime() / 1000);
+      const tranches = [
+        { timestamp: now.sub(new anchor.BN(3600)), amount: new anchor.BN(10) },
+        { timestamp: now.add(new anchor.BN(1000)), amount: new anchor.BN(90) },
+      ];
+      const { schedule } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      try {
+        const tx = await program.methods
+          .createWithSchedule(
+            identifier,
+            getName("Vault"),
+            tranches,
+            await getAuthority(Authority.Neither, program),
+            await getAuthority(Authority.Recipient, program),
+            false,
+            false
+          )
+          .accounts({
+            ...createAccounts(identifier),
+            schedule,
+          })
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("InvalidStartDate");
+        expect(e.error.errorCode.number).toStrictEqual(6017);
+      }
+    });
+  });
+
+  describe("Batch Vaults", () => {
+    it("should create a vault for each recipient in the batch", async () => {
+      const name = getName("Vault");
+      const autopay = false;
+      const allowPublicDeposits = false;
+      const totalVestingDuration = new anchor.BN(10);
+      const startDate = new anchor.BN(new Date().getTime() / 1000);
+      const payoutInterval = new anchor.BN(1);
+      const cliffDate = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
+      const cancelAuthority = await getAuthority(Authority.Neither, program);
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const vaults = [recipient, randomUser].map((user) => ({
+        user,
+        identifier: new anchor.BN(randomBytes(8)),
+        amountToBeVested: new anchor.BN(10),
+        cliffAmount: new anchor.BN(0),
+      }));
+      const { config } = await getPDAs(program.programId);
+
+      const remainingAccounts = vaults.flatMap(({ user, identifier }) => {
+        const { vault, vaultAta } = getPDAs(
+          program.programId,
+          identifier,
+          creator.publicKey,
+          mint
+        );
+
+        return [
+          { pubkey: user.publicKey, isSigner: false, isWritable: false },
+          { pubkey: vault, isSigner: false, isWritable: true },
+          { pubkey: vaultAta, isSigner: false, isWritable: true },
+        ];
+      });
+
+      const tx = await program.methods
+        .createBatch(
+          name,
+          vaults.map(({ identifier, amountToBeVested, cliffAmount }) => ({
+            identifier,
+            amountToBeVested,
+            cliffAmount,
+          })),
+          totalVestingDuration,
+          startDate,
+          payoutInterval,
+          cliffDate,
+          vestingMode,
+          cancelAuthority,
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
+        )
+        .accounts({
+          creator: creator.publicKey,
+          config,
+          feeVault,
+          feeStats,
+          mintFeeOverride,
+          creatorAta: creatorTokenAccount.address,
+          creatorGovernanceAta: creatorGovernanceAta.address,
+          mint,
+          governanceTokenMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          governanceTokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      for (const { user, identifier } of vaults) {
+        const { vault } = getPDAs(
+          program.programId,
+          identifier,
+          creator.publicKey,
+          mint
+        );
+        const vaultAccount = await program.account.vault.fetch(vault);
+
+        expect(vaultAccount.identifier.toString()).toStrictEqual(
+          identifier.toString()
+        );
+        expect(vaultAccount.recipient.toString()).toStrictEqual(
+          user.publicKey.toString()
+        );
+      }
+    });
+
+    it("should create a vault whose address already holds lamports", async () => {
+      const batchIdentifier = new anchor.BN(randomBytes(8));
+      const { config } = await getPDAs(program.programId);
+      const { vault, vaultAta } = getPDAs(
+        program.programId,
+        batchIdentifier,
+        creator.publicKey,
+        mint
+      );
+
+      await provider.sendAndConfirm(
+        new anchor.web3.Transaction().add(
+          anchor.web3.SystemProgram.transfer({
+            fromPubkey: payer.publicKey,
+            toPubkey: vault,
+            lamports: 1_000_000,
+          })
+        )
+      );
+
+      const tx = await program.methods
+        .createBatch(
+          getName("Vault"),
+          [
+            {
+              identifier: batchIdentifier,
+              amountToBeVested: new anchor.BN(10),
+              cliffAmount: new anchor.BN(0),
+            },
+          ],
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts({
+          creator: creator.publicKey,
+          config,
+          feeVault,
+          feeStats,
+          mintFeeOverride,
+          creatorAta: creatorTokenAccount.address,
+          creatorGovernanceAta: creatorGovernanceAta.address,
+          mint,
+          governanceTokenMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          governanceTokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: recipient.publicKey, isSigner: false, isWritable: false },
+          { pubkey: vault, isSigner: false, isWritable: true },
+          { pubkey: vaultAta, isSigner: false, isWritable: true },
+        ])
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+
+      expect(vaultAccount.identifier.toString()).toStrictEqual(
+        batchIdentifier.toString()
+      );
+    });
+  });
+
+  describe("Transfer Recipient", () => {
+    it("should let the recipient transfer the vault", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const name = getName("Vault");
+      const autopay = false;
+      const allowPublicDeposits = false;
+      const amountToBeVested = new anchor.BN(100);
+      const totalVestingDuration = new anchor.BN(10);
+      const startDate = new anchor.BN(new Date().getTime() / 1000);
+      const payoutInterval = new anchor.BN(1);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
+      const cancelAuthority = await getAuthority(Authority.Neither, program);
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { vault } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      let tx = await program.methods
+        .create(
+          identifier,
+          name,
+          amountToBeVested,
+          totalVestingDuration,
+          startDate,
+          payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
+          cancelAuthority,
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      tx = await program.methods
+        .transferRecipient()
+        .accounts({
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          newRecipient: randomUser.publicKey,
+          vault,
+        })
+        .signers([recipient])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+
+      expect(vaultAccount.recipient.toString()).toStrictEqual(
+        randomUser.publicKey.toString()
+      );
+    });
+
+    it("should not let the creator transfer the vault", async () => {
+      try {
+        const { vault } = await getPDAs(
+          program.programId,
+          identifier,
+          creator.publicKey,
+          mint
+        );
+
+        const tx = await program.methods
+          .transferRecipient()
+          .accounts({
+            creator: creator.publicKey,
+            recipient: randomUser.publicKey,
+            newRecipient: creator.publicKey,
+            vault,
+          })
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("Unauthorized");
+        expect(e.error.errorCode.number).toStrictEqual(6001);
+      }
+    });
+
+    it("should not create a vault that only the creator can transfer", async () => {
+      const creatorIdentifier = new anchor.BN(randomBytes(8));
+
+      try {
+        const tx = await program.methods
+          .create(
+            creatorIdentifier,
+            getName("Vault"),
+            new anchor.BN(100),
+            new anchor.BN(10),
+            new anchor.BN(new Date().getTime() / 1000),
+            new anchor.BN(1),
+            new anchor.BN(0),
+            new anchor.BN(0),
+            await getVestingMode(VestingMode.Interval, program),
+            await getAuthority(Authority.Neither, program),
+            await getAuthority(Authority.Creator, program),
+            false,
+            false
+          )
+          .accounts(createAccounts(creatorIdentifier))
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual(
+          "InvalidTransferAuthority"
+        );
+        expect(e.error.errorCode.number).toStrictEqual(6024);
+      }
+    });
+
+    it("should not disburse to the previous recipient", async () => {
+      try {
+
+        const tx = await program.methods
+          .disburse()
+          .accounts(
+            disburseAccounts(
+              identifier,
+              randomUser.publicKey,
+              userRewardAta.address
+            )
+          )
+          .signers([randomUser])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("ConstraintRaw");
+        expect(e.error.errorCode.number).toStrictEqual(2003);
+      }
+    });
+  });
+
+  describe("Deposit", () => {
+    it("should extend the schedule of a vault", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const name = getName("Vault");
+      const autopay = false;
+      const allowPublicDeposits = false;
+      const amountToBeVested = new anchor.BN(100);
+      const totalVestingDuration = new anchor.BN(1000);
+      const startDate = new anchor.BN(new Date().getTime() / 1000);
+      const payoutInterval = new anchor.BN(100);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
+      const cancelAuthority = await getAuthority(Authority.Neither, program);
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { config, vault, vaultAta } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      let tx = await program.methods
+        .create(
+          identifier,
+          name,
+          amountToBeVested,
+          totalVestingDuration,
+          startDate,
+          payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
+          cancelAuthority,
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultBefore = await program.account.vault.fetch(vault);
+      const depositMode = await getDepositMode(
+        DepositMode.ExtendSchedule,
+        program
+      );
+
+      tx = await program.methods
+        .deposit(new anchor.BN(50), depositMode)
+        .accounts({
+          depositor: creator.publicKey,
+          creator: creator.publicKey,
+          config,
+          vault,
+          vaultAta,
+          depositorAta: creatorTokenAccount.address,
+          feeVault,
+          feeStats,
+          mintFeeOverride,
+          mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAfter = await program.account.vault.fetch(vault);
+
+      expect(vaultAfter.totalNumberOfPayouts.toNumber()).toBeGreaterThan(
+        vaultBefore.totalNumberOfPayouts.toNumber()
+      );
+      expect(vaultAfter.initialDepositAmount.toNumber()).toBeGreaterThan(
+        vaultBefore.initialDepositAmount.toNumber()
+      );
+    });
+
+    it("should not move the end date for a deposit smaller than one payout", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const { config, vault, vaultAta } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      let tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(1000),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(100),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultBefore = await program.account.vault.fetch(vault);
+
+      // Each of the 10 payouts releases about 10 tokens, so 5 tokens do not cover one.
+      tx = await program.methods
+        .deposit(
+          new anchor.BN(5),
+          await getDepositMode(DepositMode.ExtendSchedule, program)
+        )
+        .accounts({
+          depositor: creator.publicKey,
+          creator: creator.publicKey,
+          config,
+          vault,
+          vaultAta,
+          depositorAta: creatorTokenAccount.address,
+          feeVault,
+          feeStats,
+          mintFeeOverride,
+          mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAfter = await program.account.vault.fetch(vault);
+
+      expect(vaultAfter.totalNumberOfPayouts.toString()).toStrictEqual(
+        vaultBefore.totalNumberOfPayouts.toString()
+      );
+      expect(vaultAfter.totalVestingDuration.toString()).toStrictEqual(
+        vaultBefore.totalVestingDuration.toString()
+      );
+      expect(vaultAfter.initialDepositAmount.toNumber()).toBeGreaterThan(
+        vaultBefore.initialDepositAmount.toNumber()
+      );
+    });
+
+    it("should not let anyone else deposit", async () => {
+      try {
+        const { config, vault, vaultAta } = await getPDAs(
+          program.programId,
+          identifier,
+          creator.publicKey,
+          mint
+        );
+        const depositMode = await getDepositMode(
+          DepositMode.IncreasePayouts,
+          program
+        );
+
+        const tx = await program.methods
+          .deposit(new anchor.BN(1), depositMode)
+          .accounts({
+            depositor: recipient.publicKey,
+            creator: creator.publicKey,
+            config,
+            vault,
+            vaultAta,
+            depositorAta: recipientTokenAccount.address,
+            feeVault,
+            feeStats,
+            mintFeeOverride,
+            mint,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([recipient])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("Unauthorized");
+        expect(e.error.errorCode.number).toStrictEqual(6001);
+      }
+    });
+  });
+
+  describe("Vault w/ Transfer Fee Mint", () => {
+    const transferFeeBasisPoints = 500;
+    let feeMint: PublicKey;
+    let feeMintCreatorAta: Account;
+    let feeMintFeeVault: PublicKey;
+    let feeMintFeeStats: PublicKey;
+    let feeMintFeeOverride: PublicKey;
+
+    beforeAll(async () => {
+      [feeMint, feeMintCreatorAta] = await mintTransferFeeTokens(
+        provider.connection,
+        payer,
+        decimals,
+        transferFeeBasisPoints,
+        BigInt(1_000_000 * 10 ** decimals),
+        creator,
+        recipient,
+        1_000
+      );
+
+      ({
+        feeVault: feeMintFeeVault,
+        feeStats: feeMintFeeStats,
+        mintFeeOverride: feeMintFeeOverride,
+      } = getFeePDAs(program.programId, feeMint));
+    }, 30000);
+
+    it("should record what a scheduled vault actually receives", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const now = new anchor.BN(new Date().getTime() / 1000);
+      const tranches = [
+        { timestamp: now, amount: new anchor.BN(10) },
+        { timestamp: now.add(new anchor.BN(1000)), amount: new anchor.BN(90) },
+      ];
+      const { config, vault, vaultAta, schedule } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        feeMint
+      );
+
+      const tx = await program.methods
+        .createWithSchedule(
+          identifier,
+          getName("Vault"),
+          tranches,
+          await getAuthority(Authority.Creator, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts({
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          config,
+          vault,
+          vaultAta,
+          schedule,
+          feeVault: feeMintFeeVault,
+          feeStats: feeMintFeeStats,
+          mintFeeOverride: feeMintFeeOverride,
+          creatorAta: feeMintCreatorAta.address,
+          creatorGovernanceAta: creatorGovernanceAta.address,
+          mint: feeMint,
+          governanceTokenMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          governanceTokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+      const scheduleAccount = await program.account.schedule.fetch(schedule);
+      const vaultAtaAccount = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const trancheTotal = scheduleAccount.tranches.reduce(
+        (total, tranche) => total.add(tranche.amount),
+        new anchor.BN(0)
+      );
+
+      // The transfer fee leaves the vault short of the tranche amounts it was created with...
+      expect(vaultAtaAccount.amount).toBeLessThan(
+        BigInt(100 * 10 ** decimals)
+      );
+      // ...so the vault records what it received and the schedule adds up to it.
+      expect(vaultAccount.initialDepositAmount.toString()).toStrictEqual(
+        vaultAtaAccount.amount.toString()
+      );
+      expect(trancheTotal.toString()).toStrictEqual(
+        vaultAccount.initialDepositAmount.toString()
+      );
+    });
+
+    it("should record what a deposit actually adds to the vault", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const { config, vault, vaultAta } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        feeMint
+      );
+
+      let tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(1000),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(100),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Creator, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts({
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          config,
+          vault,
+          vaultAta,
+          feeVault: feeMintFeeVault,
+          feeStats: feeMintFeeStats,
+          mintFeeOverride: feeMintFeeOverride,
+          creatorAta: feeMintCreatorAta.address,
+          creatorGovernanceAta: creatorGovernanceAta.address,
+          mint: feeMint,
+          governanceTokenMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          governanceTokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAtaBefore = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      tx = await program.methods
+        .deposit(
+          new anchor.BN(50),
+          await getDepositMode(DepositMode.IncreasePayouts, program)
+        )
+        .accounts({
+          depositor: creator.publicKey,
+          creator: creator.publicKey,
+          config,
+          vault,
+          vaultAta,
+          depositorAta: feeMintCreatorAta.address,
+          feeVault: feeMintFeeVault,
+          feeStats: feeMintFeeStats,
+          mintFeeOverride: feeMintFeeOverride,
+          mint: feeMint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+      const vaultAtaAfter = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      expect(vaultAtaAfter.amount - vaultAtaBefore.amount).toBeLessThan(
+        BigInt(50 * 10 ** decimals)
+      );
+      expect(vaultAccount.initialDepositAmount.toString()).toStrictEqual(
+        vaultAtaAfter.amount.toString()
+      );
+    });
+  });
+
+  describe("Vault w/ Beneficiaries", () => {
+    it("should split each payout between the beneficiaries", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const name = getName("Vault");
+      const autopay = false;
+      const allowPublicDeposits = false;
+      const amountToBeVested = new anchor.BN(100);
+      const totalVestingDuration = new anchor.BN(10);
+      const startDate = new anchor.BN(new Date().getTime() / 1000);
+      const payoutInterval = new anchor.BN(1);
+      const cliffDate = new anchor.BN(0);
+      const cliffAmount = new anchor.BN(0);
+      const vestingMode = await getVestingMode(VestingMode.Interval, program);
+      const cancelAuthority = await getAuthority(Authority.Creator, program);
+      const transferAuthority = await getAuthority(
+        Authority.Recipient,
+        program
+      );
+      const { vault, beneficiaries } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      const randomUserTokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        randomUser,
+        mint,
+        randomUser.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+
+      let tx = await program.methods
+        .create(
+          identifier,
+          name,
+          amountToBeVested,
+          totalVestingDuration,
+          startDate,
+          payoutInterval,
+          cliffDate,
+          cliffAmount,
+          vestingMode,
+          cancelAuthority,
+          transferAuthority,
+          autopay,
+          allowPublicDeposits
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      tx = await program.methods
+        .createBeneficiaries([
+          {
+            recipient: recipient.publicKey,
+            shareBasisPoints: new anchor.BN(5000),
+          },
+          {
+            recipient: randomUser.publicKey,
+            shareBasisPoints: new anchor.BN(5000),
+          },
+        ])
+        .accounts({
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          vault,
+          beneficiaries,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator, recipient])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const recipientBefore = await getAccount(
+        provider.connection,
+        recipientTokenAccount.address,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      await sleep(2000);
+      tx = await program.methods
+        .disburse()
+        .accounts({
+          ...disburseAccounts(
+            identifier,
+            creator.publicKey,
+            creatorGovernanceAta.address
+          ),
+          beneficiaries,
+        })
+        .remainingAccounts([
+          {
+            pubkey: recipientTokenAccount.address,
+            isSigner: false,
+            isWritable: true,
+          },
+          {
+            pubkey: randomUserTokenAccount.address,
+            isSigner: false,
+            isWritable: true,
+          },
+        ])
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const recipientAfter = await getAccount(
+        provider.connection,
+        recipientTokenAccount.address,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const randomUserAfter = await getAccount(
+        provider.connection,
+        randomUserTokenAccount.address,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      expect(Number(recipientAfter.amount)).toBeGreaterThan(
+        Number(recipientBefore.amount)
+      );
+      expect(Number(randomUserAfter.amount)).toBeGreaterThan(0);
+    });
+
+    it("should close the beneficiaries when the vault is cancelled", async () => {
+      const { vault, vaultAta, beneficiaries } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      const randomUserTokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        randomUser,
+        mint,
+        randomUser.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+      const cancelAccounts = {
+        signer: creator.publicKey,
+        creator: creator.publicKey,
+        recipient: recipient.publicKey,
+        vault,
+        vaultAta,
+        creatorAta: creatorTokenAccount.address,
+        recipientAta: recipientTokenAccount.address,
+        mint,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      };
+      const beneficiaryAtas = [
+        {
+          pubkey: recipientTokenAccount.address,
+          isSigner: false,
+          isWritable: true,
+        },
+        {
+          pubkey: randomUserTokenAccount.address,
+          isSigner: false,
+          isWritable: true,
+        },
+      ];
+
+      try {
+        const tx = await program.methods
+          .cancel()
+          .accounts(cancelAccounts)
+          .remainingAccounts(beneficiaryAtas)
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("InvalidBeneficiaries");
+        expect(e.error.errorCode.number).toStrictEqual(6012);
+      }
+
+      const tx = await program.methods
+        .cancel()
+        .accounts({ ...cancelAccounts, beneficiaries })
+        .remainingAccounts(beneficiaryAtas)
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      expect(
+        await provider.connection.getAccountInfo(beneficiaries)
+      ).toBeNull();
+    });
+
+    it("should not split a vault that cannot be cancelled", async () => {
+      const irrevocableIdentifier = new anchor.BN(randomBytes(8));
+      const { vault, beneficiaries } = await getPDAs(
+        program.programId,
+        irrevocableIdentifier,
+        creator.publicKey,
+        mint
+      );
+
+      const tx = await program.methods
+        .create(
+          irrevocableIdentifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(createAccounts(irrevocableIdentifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      try {
+        const tx = await program.methods
+          .createBeneficiaries([
+            {
+              recipient: creator.publicKey,
+              shareBasisPoints: new anchor.BN(10000),
+            },
+          ])
+          .accounts({
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            vault,
+            beneficiaries,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([creator, recipient])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("Unauthorized");
+        expect(e.error.errorCode.number).toStrictEqual(6001);
+      }
+    });
+  });
+
+  describe("Mint Extension Policy", () => {
+    it("should not create a vault for a mint with a denied extension", async () => {
+      const { config } = await getPDAs(program.programId);
+      const denylist = await getMintExtensionPolicy(
+        MintExtensionPolicy.Denylist,
+        program
+      );
+
+      let tx = await program.methods
+        .updateMintExtensionPolicy(
+          denylist,
+          new anchor.BN(
+            defaultDeniedMintExtensions | transferFeeConfigExtension
+          ),
+          true
+        )
+        .accounts({
+          admin: payer.publicKey,
+          config,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      try {
+        identifier = new anchor.BN(randomBytes(8));
+
+        tx = await program.methods
+          .create(
+            identifier,
+            getName("Vault"),
+            new anchor.BN(100),
+            new anchor.BN(10),
+            new anchor.BN(new Date().getTime() / 1000),
+            new anchor.BN(1),
+            new anchor.BN(0),
+            new anchor.BN(0),
+            await getVestingMode(VestingMode.Interval, program),
+            await getAuthority(Authority.Neither, program),
+            await getAuthority(Authority.Recipient, program),
+            false,
+            false
+          )
+          .accounts(createAccounts(identifier))
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("InvalidMintExtensions");
+        expect(e.error.errorCode.number).toStrictEqual(6013);
+      }
+
+      tx = await program.methods
+        .updateMintExtensionPolicy(
+          denylist,
+          new anchor.BN(defaultDeniedMintExtensions),
+          true
+        )
+        .accounts({
+          admin: payer.publicKey,
+          config,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const configAccount = await program.account.config.fetch(config);
+      expect(configAccount.mintExtensions.toNumber()).toStrictEqual(
+        defaultDeniedMintExtensions
+      );
+    });
+  });
+
+  describe("Native SOL Vault", () => {
+    it("should vest and disburse SOL without wrapping", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const { config, vault } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        NATIVE_MINT
+      );
+
+      let tx = await program.methods
+        .createSol(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(1),
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Creator, program),
+          await getAuthority(Authority.Recipient, program),
+          false
+        )
+        .accounts({
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          config,
+          feeStats: getNativeFeeStatsPDA(program.programId),
+          mintFeeOverride: getFeePDAs(program.programId, NATIVE_MINT)
+            .mintFeeOverride,
+          vault,
+          creatorGovernanceAta: creatorGovernanceAta.address,
+          governanceTokenMint,
+          governanceTokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+      expect(vaultAccount.isNative).toStrictEqual(true);
+      expect(vaultAccount.mint.toString()).toStrictEqual(
+        NATIVE_MINT.toString()
+      );
+
+      const recipientBalanceBefore = await provider.connection.getBalance(
+        recipient.publicKey
+      );
+
+      await sleep(2000);
+
+      // Native vaults report the lamports they hold, without a token account.
+      const claimable = await program.methods
+        .getClaimable()
+        .accounts({ vault })
+        .view();
+
+      expect(claimable.amount.toNumber()).toBeGreaterThan(0);
+      expect(claimable.remainingBalance.toString()).toStrictEqual(
+        vaultAccount.initialDepositAmount.toString()
+      );
+
+      tx = await program.methods
+        .disburseSol()
+        .accounts({
+          signer: creator.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          config,
+          vault,
+          signerGovernanceAta: creatorGovernanceAta.address,
+          creatorGovernanceAta: creatorGovernanceAta.address,
+          governanceTokenMint,
+          governanceTokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const recipientBalanceAfter = await provider.connection.getBalance(
+        recipient.publicKey
+      );
+      expect(recipientBalanceAfter).toBeGreaterThan(recipientBalanceBefore);
+
+      tx = await program.methods
+        .cancelSol()
+        .accounts({
+          signer: creator.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          vault,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultInfo = await provider.connection.getAccountInfo(vault);
+      expect(vaultInfo).toBeNull();
+    });
+
+    it("should vest a fractional amount of SOL", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const amountToBeVested = new anchor.BN(0.5 * LAMPORTS_PER_SOL);
+      const { config, vault } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        NATIVE_MINT
+      );
+
+      const tx = await program.methods
+        .createSolWithLamports(
+          identifier,
+          getName("Vault"),
+          amountToBeVested,
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Creator, program),
+          await getAuthority(Authority.Recipient, program),
+          false
+        )
+        .accounts({
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          config,
+          feeStats: getNativeFeeStatsPDA(program.programId),
+          mintFeeOverride: getFeePDAs(program.programId, NATIVE_MINT)
+            .mintFeeOverride,
+          vault,
+          creatorGovernanceAta: creatorGovernanceAta.address,
+          governanceTokenMint,
+          governanceTokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+      const vaultInfo = await provider.connection.getAccountInfo(vault);
+      const rentExemptMinimum =
+        await provider.connection.getMinimumBalanceForRentExemption(
+          vaultInfo.data.length
+        );
+
+      expect(vaultAccount.initialDepositAmount.toString()).toStrictEqual(
+        (vaultInfo.lamports - rentExemptMinimum).toString()
+      );
+      expect(vaultAccount.initialDepositAmount.toNumber()).toBeLessThanOrEqual(
+        amountToBeVested.toNumber()
+      );
+    });
+
+    it("should not create a vault whose payouts are below the rent-exempt minimum", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const { config, vault } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        NATIVE_MINT
+      );
+
+      try {
+        // 10 payouts of 10000 lamports could not be paid to a new wallet.
+        const tx = await program.methods
+          .createSolWithLamports(
+            identifier,
+            getName("Vault"),
+            new anchor.BN(100_000),
+            new anchor.BN(10),
+            new anchor.BN(new Date().getTime() / 1000),
+            new anchor.BN(1),
+            new anchor.BN(0),
+            new anchor.BN(0),
+            await getVestingMode(VestingMode.Interval, program),
+            await getAuthority(Authority.Creator, program),
+            await getAuthority(Authority.Recipient, program),
+            false
+          )
+          .accounts({
+            creator: creator.publicKey,
+            recipient: recipient.publicKey,
+            config,
+            feeStats: getNativeFeeStatsPDA(program.programId),
+            mintFeeOverride: getFeePDAs(program.programId, NATIVE_MINT)
+              .mintFeeOverride,
+            vault,
+            creatorGovernanceAta: creatorGovernanceAta.address,
+            governanceTokenMint,
+            governanceTokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("InvalidAmount");
+        expect(e.error.errorCode.number).toStrictEqual(6018);
+      }
+    });
+  });
+
+  describe("Vault w/ Base Units", () => {
+    it("should vest a fractional amount of tokens", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const amountToBeVested = new anchor.BN(1.5 * 10 ** decimals);
+      const accounts = createAccounts(identifier);
+
+      const tx = await program.methods
+        .createWithBaseUnits(
+          identifier,
+          getName("Vault"),
+          amountToBeVested,
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(accounts)
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(accounts.vault);
+      const vaultAtaAccount = await getAccount(
+        provider.connection,
+        accounts.vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      expect(vaultAccount.initialDepositAmount.toString()).toStrictEqual(
+        vaultAtaAccount.amount.toString()
+      );
+      expect(vaultAccount.initialDepositAmount.toNumber()).toBeLessThan(
+        amountToBeVested.toNumber()
+      );
+    });
+
+    it("should fail with an overflow error instead of panicking", async () => {
+      try {
+        identifier = new anchor.BN(randomBytes(8));
+        const accounts = createAccounts(identifier);
+
+        const tx = await program.methods
+          .create(
+            identifier,
+            getName("Vault"),
+            new anchor.BN("18446744073709551615"),
+            new anchor.BN(10),
+            new anchor.BN(new Date().getTime() / 1000),
+            new anchor.BN(1),
+            new anchor.BN(0),
+            new anchor.BN(0),
+            await getVestingMode(VestingMode.Interval, program),
+            await getAuthority(Authority.Neither, program),
+            await getAuthority(Authority.Recipient, program),
+            false,
+            false
+          )
+          .accounts(accounts)
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("MathOverflow");
+        expect(e.error.errorCode.number).toStrictEqual(6015);
+      }
+    });
+  });
+
+  describe("Create Validation", () => {
+    const now = () => new anchor.BN(new Date().getTime() / 1000);
+    const cases = [
+      {
+        name: "a zero amount",
+        amountToBeVested: new anchor.BN(0),
+        startDate: now,
+        payoutInterval: new anchor.BN(1),
+        code: "InvalidAmount",
+        number: 6018,
+      },
+      {
+        name: "a zero payout interval",
+        amountToBeVested: new anchor.BN(100),
+        startDate: now,
+        payoutInterval: new anchor.BN(0),
+        code: "InvalidPayoutInterval",
+        number: 6016,
+      },
+      {
+        name: "a payout interval longer than the vesting duration",
+        amountToBeVested: new anchor.BN(100),
+        startDate: now,
+        payoutInterval: new anchor.BN(11),
+        code: "InvalidPayoutInterval",
+        number: 6016,
+      },
+      {
+        name: "a start date in the past",
+        amountToBeVested: new anchor.BN(100),
+        startDate: () => now().sub(new anchor.BN(3600)),
+        payoutInterval: new anchor.BN(1),
+        code: "InvalidStartDate",
+        number: 6017,
+      },
+    ];
+
+    cases.forEach((testCase) => {
+      it(`should not create a vault with ${testCase.name}`, async () => {
+        try {
+          identifier = new anchor.BN(randomBytes(8));
+
+          const tx = await program.methods
+            .create(
+              identifier,
+              getName("Vault"),
+              testCase.amountToBeVested,
+              new anchor.BN(10),
+              testCase.startDate(),
+              testCase.payoutInterval,
+              new anchor.BN(0),
+              new anchor.BN(0),
+              await getVestingMode(VestingMode.Interval, program),
+              await getAuthority(Authority.Neither, program),
+              await getAuthority(Authority.Recipient, program),
+              false,
+              false
+            )
+            .accounts(createAccounts(identifier))
+            .signers([creator])
+            .rpc();
+
+          await confirm(provider.connection, tx);
+          expect(true).toBe(false);
+        } catch (e) {
+          expect(e.error.errorCode.code).toStrictEqual(testCase.code);
+          expect(e.error.errorCode.number).toStrictEqual(testCase.number);
+        }
+      });
+    });
+  });
+
+  describe("Vault w/ Remainder", () => {
+    it("should empty the vault on the final payout", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const { vault, vaultAta } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      // Three payouts at 4, 8 and 12 seconds, with the vault expiring at 14.
+      let tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(14),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(4),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      await sleep(12500);
+      tx = await program.methods
+        .disburse()
+        .accounts(
+          disburseAccounts(
+            identifier,
+            creator.publicKey,
+            creatorGovernanceAta.address
+          )
+        )
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+      const vaultAtaAccount = await getAccount(
+        provider.connection,
+        vaultAta,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      expect(vaultAtaAccount.amount).toStrictEqual(0n);
+      expect(vaultAccount.numberOfPaymentsMade.toNumber()).toStrictEqual(
+        vaultAccount.totalNumberOfPayouts.toNumber()
+      );
+    }, 30000);
+  });
+
+  describe("Pause", () => {
+    const pauseDisburse = 4;
+
+    const updatePause = async (paused: boolean, pauseFlags: number) => {
+      const { config } = await getPDAs(program.programId);
+      const tx = await program.methods
+        .updatePause(paused, pauseFlags)
+        .accounts({
+          admin: payer.publicKey,
+          config,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+    };
+
+    const disburse = async (
+      signer: Keypair,
+      signerGovernanceAta: PublicKey
+    ) => {
+
+      const tx = await program.methods
+        .disburse()
+        .accounts(
+          disburseAccounts(identifier, signer.publicKey, signerGovernanceAta)
+        )
+        .signers([signer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+    };
+
+    it("should not create a vault while the program is paused", async () => {
+      await updatePause(true, 0);
+
+      try {
+        identifier = new anchor.BN(randomBytes(8));
+
+        const tx = await program.methods
+          .create(
+            identifier,
+            getName("Vault"),
+            new anchor.BN(100),
+            new anchor.BN(10),
+            new anchor.BN(new Date().getTime() / 1000),
+            new anchor.BN(1),
+            new anchor.BN(0),
+            new anchor.BN(0),
+            await getVestingMode(VestingMode.Interval, program),
+            await getAuthority(Authority.Neither, program),
+            await getAuthority(Authority.Recipient, program),
+            false,
+            false
+          )
+          .accounts(createAccounts(identifier))
+          .signers([creator])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("Paused");
+        expect(e.error.errorCode.number).toStrictEqual(6020);
+        expect(e.error.errorMessage).toStrictEqual("The program is paused!");
+      }
+
+      await updatePause(false, 0);
+    });
+
+    it("should only let the recipient or beneficiaries disburse while disbursing is paused", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const { vault } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      const tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+      await updatePause(false, pauseDisburse);
+      await sleep(2000);
+
+      try {
+        await disburse(randomUser, userRewardAta.address);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("Paused");
+        expect(e.error.errorCode.number).toStrictEqual(6020);
+      }
+
+      const recipientGovernanceAta = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        payer,
+        governanceTokenMint,
+        recipient.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_PROGRAM_ID
+      );
+      await disburse(recipient, recipientGovernanceAta.address);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+      expect(vaultAccount.numberOfPaymentsMade.toNumber()).toBeGreaterThan(0);
+
+      await updatePause(false, 0);
+    });
+
+    it("should let a beneficiary disburse while disbursing is paused", async () => {
+      identifier = new anchor.BN(randomBytes(8));
+      const { vault, beneficiaries } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      const randomUserTokenAccount = await getOrCreateAssociatedTokenAccount(
+        provider.connection,
+        randomUser,
+        mint,
+        randomUser.publicKey,
+        false,
+        undefined,
+        undefined,
+        TOKEN_2022_PROGRAM_ID,
+        ASSOCIATED_TOKEN_PROGRAM_ID
+      );
+
+      let tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Creator, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      tx = await program.methods
+        .createBeneficiaries([
+          {
+            recipient: recipient.publicKey,
+            shareBasisPoints: new anchor.BN(5000),
+          },
+          {
+            recipient: randomUser.publicKey,
+            shareBasisPoints: new anchor.BN(5000),
+          },
+        ])
+        .accounts({
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          vault,
+          beneficiaries,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator, recipient])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+      await updatePause(false, pauseDisburse);
+      await sleep(2000);
+
+      tx = await program.methods
+        .disburse()
+        .accounts({
+          ...disburseAccounts(
+            identifier,
+            randomUser.publicKey,
+            userRewardAta.address
+          ),
+          beneficiaries,
+        })
+        .remainingAccounts([
+          {
+            pubkey: recipientTokenAccount.address,
+            isSigner: false,
+            isWritable: true,
+          },
+          {
+            pubkey: randomUserTokenAccount.address,
+            isSigner: false,
+            isWritable: true,
+          },
+        ])
+        .signers([randomUser])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+      expect(vaultAccount.numberOfPaymentsMade.toNumber()).toBeGreaterThan(0);
+
+      await updatePause(false, 0);
+    });
+  });
+
+  describe("Withdraw Fees", () => {
+    it("should pay the collected fees to the treasuries", async () => {
+      const { config } = await getPDAs(program.programId);
+      const feeStatsBefore = await program.account.feeStats.fetch(feeStats);
+      const treasuryAtaBefore = await getAccount(
+        provider.connection,
+        treasuryTokenAccount.address,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const tx = await program.methods
+        .withdrawFees()
+        .accounts({
+          admin: payer.publicKey,
+          devTreasury: payer.publicKey,
+          daoTreasury: daoTreasury.publicKey,
+          config,
+          feeStats,
+          feeVault,
+          daoTreasuryAta: treasuryTokenAccount.address,
+          mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const feeStatsAfter = await program.account.feeStats.fetch(feeStats);
+      const feeVaultAccount = await getAccount(
+        provider.connection,
+        feeVault,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      const treasuryAtaAfter = await getAccount(
+        provider.connection,
+        treasuryTokenAccount.address,
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      expect(feeVaultAccount.amount).toStrictEqual(0n);
+      expect(Number(treasuryAtaAfter.amount)).toBeGreaterThan(
+        Number(treasuryAtaBefore.amount)
+      );
+      expect(feeStatsAfter.totalTokenFees.toString()).toStrictEqual(
+        feeStatsBefore.totalTokenFees.toString()
+      );
+      expect(feeStatsAfter.withdrawnTokenFees.toString()).toStrictEqual(
+        feeStatsAfter.totalTokenFees.toString()
+      );
+      expect(feeStatsAfter.withdrawnSolFees.toString()).toStrictEqual(
+        feeStatsAfter.totalSolFees.toString()
+      );
+    });
+
+    it("should pay the fees collected from native vaults", async () => {
+      const { config } = await getPDAs(program.programId);
+      const solFeeStats = getNativeFeeStatsPDA(program.programId);
+      const daoTreasuryBalanceBefore = await provider.connection.getBalance(
+        daoTreasury.publicKey
+      );
+
+      const tx = await program.methods
+        .withdrawSolFees()
+        .accounts({
+          admin: payer.publicKey,
+          devTreasury: payer.publicKey,
+          daoTreasury: daoTreasury.publicKey,
+          config,
+          feeStats: solFeeStats,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const feeStatsAccount = await program.account.feeStats.fetch(solFeeStats);
+      const daoTreasuryBalanceAfter = await provider.connection.getBalance(
+        daoTreasury.publicKey
+      );
+
+      expect(daoTreasuryBalanceAfter).toBeGreaterThan(daoTreasuryBalanceBefore);
+      expect(feeStatsAccount.withdrawnSolFees.toString()).toStrictEqual(
+        feeStatsAccount.totalSolFees.toString()
+      );
+    });
+
+    it("should not let a non-admin withdraw the fees", async () => {
+      try {
+        const { config } = await getPDAs(program.programId);
+        const tx = await program.methods
+          .withdrawFees()
+          .accounts({
+            admin: randomUser.publicKey,
+            devTreasury: payer.publicKey,
+            daoTreasury: daoTreasury.publicKey,
+            config,
+            feeStats,
+            feeVault,
+            daoTreasuryAta: treasuryTokenAccount.address,
+            mint,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([randomUser])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("ConstraintHasOne");
+        expect(e.error.errorCode.number).toStrictEqual(2001);
+      }
+    });
+  });
+
+  describe("Mint Fee Override", () => {
+    it("should not charge fees for a mint with a fee waiver", async () => {
+      const { config } = await getPDAs(program.programId);
+
+      let tx = await program.methods
+        .setMintFeeOverride(new anchor.BN(0), new anchor.BN(0), true)
+        .accounts({
+          admin: payer.publicKey,
+          config,
+          mintFeeOverride,
+          mint,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      identifier = new anchor.BN(randomBytes(8));
+      const feeStatsBefore = await program.account.feeStats.fetch(feeStats);
+
+      tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      // The waiver covers deposits as well.
+      const { vault, vaultAta } = getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+      tx = await program.methods
+        .deposit(
+          new anchor.BN(10),
+          await getDepositMode(DepositMode.IncreasePayouts, program)
+        )
+        .accounts({
+          depositor: creator.publicKey,
+          creator: creator.publicKey,
+          config,
+          vault,
+          vaultAta,
+          depositorAta: creatorTokenAccount.address,
+          feeVault,
+          feeStats,
+          mintFeeOverride,
+          mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const feeStatsAfter = await program.account.feeStats.fetch(feeStats);
+      expect(feeStatsAfter.totalTokenFees.toString()).toStrictEqual(
+        feeStatsBefore.totalTokenFees.toString()
+      );
+      expect(feeStatsAfter.totalSolFees.toString()).toStrictEqual(
+        feeStatsBefore.totalSolFees.toString()
+      );
+
+      tx = await program.methods
+        .closeMintFeeOverride()
+        .accounts({
+          admin: payer.publicKey,
+          config,
+          mintFeeOverride,
+          mint,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const mintFeeOverrideInfo = await provider.connection.getAccountInfo(
+        mintFeeOverride
+      );
+      expect(mintFeeOverrideInfo).toBeNull();
+    });
+
+    it("should not let a non-admin set a fee override", async () => {
+      try {
+        const { config } = await getPDAs(program.programId);
+  
+        const tx = await program.methods
+          .setMintFeeOverride(new anchor.BN(0), new anchor.BN(0), true)
+          .accounts({
+            admin: randomUser.publicKey,
+            config,
+            mintFeeOverride,
+            mint,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([randomUser])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("ConstraintHasOne");
+        expect(e.error.errorCode.number).toStrictEqual(2001);
+      }
+    });
+
+    it("should not set a fee override above the fees in the config", async () => {
+      try {
+        const { config } = await getPDAs(program.programId);
+        const configAccount = await program.account.config.fetch(config);
+
+        const tx = await program.methods
+          .setMintFeeOverride(
+            configAccount.tokenFeeBasisPoints.add(new anchor.BN(1)),
+            configAccount.devFee,
+            false
+          )
+          .accounts({
+            admin: payer.publicKey,
+            config,
+            mintFeeOverride,
+            mint,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([payer])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual(
+          "InvalidTokenFeeBasisPoints"
+        );
+      }
+    });
+  });
+
+  describe("Fee Discount Tiers", () => {
+    it("should discount the fees of governance token holders", async () => {
+      const { config } = await getPDAs(program.programId);
+
+      let tx = await program.methods
+        .updateFeeDiscountTiers([
+          {
+            minGovernanceTokenAmount: new anchor.BN(1),
+            discountBasisPoints: new anchor.BN(5000),
+          },
+        ])
+        .accounts({
+          admin: payer.publicKey,
+          config,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      identifier = new anchor.BN(randomBytes(8));
+      const configAccount = await program.account.config.fetch(config);
+      const feeStatsBefore = await program.account.feeStats.fetch(feeStats);
+
+      tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false,
+          false
+        )
+        .accounts(createAccounts(identifier))
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const feeStatsAfter = await program.account.feeStats.fetch(feeStats);
+      expect(
+        feeStatsAfter.totalSolFees.sub(feeStatsBefore.totalSolFees).toString()
+      ).toStrictEqual(configAccount.devFee.divn(2).toString());
+
+      const { vault, vaultAta } = getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+      const depositAmount = new anchor.BN(10 * 10 ** decimals);
+
+      tx = await program.methods
+        .deposit(
+          new anchor.BN(10),
+          await getDepositMode(DepositMode.IncreasePayouts, program)
+        )
+        .accounts({
+          depositor: creator.publicKey,
+          creator: creator.publicKey,
+          config,
+          vault,
+          vaultAta,
+          depositorAta: creatorTokenAccount.address,
+          depositorGovernanceAta: creatorGovernanceAta.address,
+          feeVault,
+          feeStats,
+          mintFeeOverride,
+          mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultDeposited = await getEvent(program, tx, "VaultDeposited");
+      expect(vaultDeposited.tokenFeeAmount.toString()).toStrictEqual(
+        depositAmount
+          .mul(configAccount.tokenFeeBasisPoints.divn(2))
+          .divn(10_000)
+          .toString()
+      );
+
+      identifier = new anchor.BN(randomBytes(8));
+      const { vault: solVault } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        NATIVE_MINT
+      );
+      const nativeFeeStats = getNativeFeeStatsPDA(program.programId);
+      const nativeFeeStatsBefore = await program.account.feeStats.fetch(
+        nativeFeeStats
+      );
+
+      tx = await program.methods
+        .createSolWithLamports(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(0.5 * LAMPORTS_PER_SOL),
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(1),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(VestingMode.Interval, program),
+          await getAuthority(Authority.Neither, program),
+          await getAuthority(Authority.Recipient, program),
+          false
+        )
+        .accounts({
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          config,
+          feeStats: nativeFeeStats,
+          mintFeeOverride: getFeePDAs(program.programId, NATIVE_MINT)
+            .mintFeeOverride,
+          vault: solVault,
+          creatorGovernanceAta: creatorGovernanceAta.address,
+          governanceTokenMint,
+          governanceTokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const nativeFeeStatsAfter = await program.account.feeStats.fetch(
+        nativeFeeStats
+      );
+      expect(
+        nativeFeeStatsAfter.totalSolFees
+          .sub(nativeFeeStatsBefore.totalSolFees)
+          .toString()
+      ).toStrictEqual(configAccount.devFee.divn(2).toString());
+
+      tx = await program.methods
+        .updateFeeDiscountTiers([])
+        .accounts({
+          admin: payer.publicKey,
+          config,
+        })
+        .signers([payer])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+    });
+
+    it("should not accept tiers out of order", async () => {
+      try {
+        const { config } = await getPDAs(program.programId);
+        const tx = await program.methods
+          .updateFeeDiscountTiers([
+            {
+              minGovernanceTokenAmount: new anchor.BN(2),
+              discountBasisPoints: new anchor.BN(1000),
+            },
+            {
+              minGovernanceTokenAmount: new anchor.BN(1),
+              discountBasisPoints: new anchor.BN(2000),
+            },
+          ])
+          .accounts({
+            admin: payer.publicKey,
+            config,
+          })
+          .signers([payer])
+          .rpc();
+
+        await confirm(provider.connection, tx);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("InvalidFeeDiscountTiers");
+        expect(e.error.errorCode.number).toStrictEqual(6021);
+      }
+    });
+  });
+
+  describe("Autopay Escrow", () => {
+    const createAutopayVault = async (
+      withEscrow: boolean,
+      vestingMode = VestingMode.Interval,
+      payoutInterval = 1
+    ) => {
+      identifier = new anchor.BN(randomBytes(8));
+      const { config, vault, vaultAta, autopayEscrow } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      const tx = await program.methods
+        .create(
+          identifier,
+          getName("Vault"),
+          new anchor.BN(100),
+          new anchor.BN(10),
+          new anchor.BN(new Date().getTime() / 1000),
+          new anchor.BN(payoutInterval),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          await getVestingMode(vestingMode, program),
+          await getAuthority(Authority.Creator, program),
+          await getAuthority(Authority.Recipient, program),
+          true,
+          false
+        )
+        .accounts({
+          ...createAccounts(identifier),
+          autopayEscrow: withEscrow ? autopayEscrow : null,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+    };
+
+    const disburseAutopayVault = async () => {
+      const { autopayEscrow } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      const tx = await program.methods
+        .disburse()
+        .accounts({
+          ...disburseAccounts(
+            identifier,
+            randomUser.publicKey,
+            userRewardAta.address
+          ),
+          autopayEscrow,
+        })
+        .signers([randomUser])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+    };
+
+    it("should not create an autopay vault without an escrow", async () => {
+      try {
+        await createAutopayVault(false);
+        expect(true).toBe(false);
+      } catch (e) {
+        expect(e.error.errorCode.code).toStrictEqual("InvalidAutopayEscrow");
+        expect(e.error.errorCode.number).toStrictEqual(6022);
+      }
+    });
+
+    it("should fund the escrow when the vault is created", async () => {
+      await createAutopayVault(true);
+
+      const { vault, autopayEscrow } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+      const vaultAccount = await program.account.vault.fetch(vault);
+      const escrowAccount = await program.account.autopayEscrow.fetch(
+        autopayEscrow
+      );
+      const escrowBalance = await provider.connection.getBalance(autopayEscrow);
+
+      expect(escrowAccount.vault.toBase58()).toStrictEqual(vault.toBase58());
+      expect(escrowAccount.lamportsPerDisburse.toNumber()).toBeGreaterThan(0);
+      expect(escrowBalance).toBeGreaterThan(
+        escrowAccount.lamportsPerDisburse
+          .mul(vaultAccount.totalNumberOfPayouts)
+          .toNumber()
+      );
+    });
+
+    it("should pay and reward whoever disburses out of the escrow", async () => {
+      const { config, vault, autopayEscrow } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+      const configAccount = await program.account.config.fetch(config);
+      const escrowBalanceBefore = await provider.connection.getBalance(
+        autopayEscrow
+      );
+      const signerGovernanceBefore = await getAccount(
+        provider.connection,
+        userRewardAta.address
+      );
+
+      await sleep(2000);
+      const tx = await program.methods
+        .disburse()
+        .accounts({
+          ...disburseAccounts(
+            identifier,
+            randomUser.publicKey,
+            userRewardAta.address
+          ),
+          autopayEscrow,
+        })
+        .signers([randomUser])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const escrowAccount = await program.account.autopayEscrow.fetch(
+        autopayEscrow
+      );
+      const escrowBalanceAfter = await provider.connection.getBalance(
+        autopayEscrow
+      );
+
+      const signerGovernanceAfter = await getAccount(
+        provider.connection,
+        userRewardAta.address
+      );
+      const crankReward =
+        signerGovernanceAfter.amount - signerGovernanceBefore.amount;
+
+      const vaultAccount = await program.account.vault.fetch(vault);
+      expect(escrowAccount.numberOfPayoutsPaid.toString()).toStrictEqual(
+        vaultAccount.numberOfPaymentsMade.toString()
+      );
+      expect(escrowBalanceBefore - escrowBalanceAfter).toStrictEqual(
+        escrowAccount.lamportsPerDisburse
+          .mul(escrowAccount.numberOfPayoutsPaid)
+          .toNumber()
+      );
+      expect(escrowAccount.lamportsPerDisburse.toNumber()).toBeGreaterThan(
+        configAccount.crankRewardLamports.toNumber()
+      );
+      expect(crankReward.toString()).toStrictEqual(
+        configAccount.crankRewardGovernanceTokenAmount
+          .mul(escrowAccount.numberOfPayoutsPaid)
+          .toString()
+      );
+    });
+
+    it("should refund the escrow when the vault is cancelled", async () => {
+      const { vault, vaultAta, autopayEscrow } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+
+      await harvestWithheldTokensToMint(
+        provider.connection,
+        creator,
+        mint,
+        [vaultAta],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+
+      const tx = await program.methods
+        .cancel()
+        .accounts({
+          signer: creator.publicKey,
+          creator: creator.publicKey,
+          recipient: recipient.publicKey,
+          vault,
+          vaultAta,
+          autopayEscrow,
+          creatorAta: creatorTokenAccount.address,
+          recipientAta: recipientTokenAccount.address,
+          mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const escrowAccountInfo = await provider.connection.getAccountInfo(
+        autopayEscrow
+      );
+      expect(escrowAccountInfo).toBeNull();
+    });
+
+    it("should only pay the escrow for payout intervals that have completed", async () => {
+      await createAutopayVault(true, VestingMode.Continuous, 4);
+
+      const { autopayEscrow } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+      const escrowAccount = await program.account.autopayEscrow.fetch(
+        autopayEscrow
+      );
+      const escrowBalanceBefore = await provider.connection.getBalance(
+        autopayEscrow
+      );
+
+      await sleep(4500);
+      await disburseAutopayVault();
+
+      const escrowBalanceAfterFirst = await provider.connection.getBalance(
+        autopayEscrow
+      );
+
+      await sleep(1000);
+      await disburseAutopayVault();
+
+      const escrowBalanceAfterSecond = await provider.connection.getBalance(
+        autopayEscrow
+      );
+
+      expect(escrowBalanceBefore - escrowBalanceAfterFirst).toStrictEqual(
+        escrowAccount.lamportsPerDisburse.toNumber()
+      );
+      expect(escrowBalanceAfterSecond).toStrictEqual(escrowBalanceAfterFirst);
+    }, 30000);
+
+    it("should only tip the crank for payout intervals that have completed", async () => {
+      await createAutopayVault(true, VestingMode.Continuous, 4);
+
+      const { config } = await getPDAs(program.programId);
+      const configAccount = await program.account.config.fetch(config);
+      const getSignerGovernanceBalance = async () =>
+        (await getAccount(provider.connection, userRewardAta.address)).amount;
+
+      const signerGovernanceBefore = await getSignerGovernanceBalance();
+
+      await sleep(1000);
+      await disburseAutopayVault();
+
+      const signerGovernanceAfterEarly = await getSignerGovernanceBalance();
+
+      await sleep(4000);
+      await disburseAutopayVault();
+
+      const signerGovernanceAfterInterval = await getSignerGovernanceBalance();
+
+      expect(signerGovernanceAfterEarly).toStrictEqual(signerGovernanceBefore);
+      expect(
+        (signerGovernanceAfterInterval - signerGovernanceAfterEarly).toString()
+      ).toStrictEqual(
+        configAccount.crankRewardGovernanceTokenAmount.toString()
+      );
+    }, 30000);
+
+    it("should fund the escrow for the payouts added by a deposit", async () => {
+      await createAutopayVault(true);
+
+      const { config, vault, vaultAta, autopayEscrow } = await getPDAs(
+        program.programId,
+        identifier,
+        creator.publicKey,
+        mint
+      );
+      const vaultBefore = await program.account.vault.fetch(vault);
+      const escrowAccount = await program.account.autopayEscrow.fetch(
+        autopayEscrow
+      );
+      const escrowBalanceBefore = await provider.connection.getBalance(
+        autopayEscrow
+      );
+
+      const tx = await program.methods
+        .deposit(
+          new anchor.BN(50),
+          await getDepositMode(DepositMode.ExtendSchedule, program)
+        )
+        .accounts({
+          depositor: creator.publicKey,
+          creator: creator.publicKey,
+          config,
+          vault,
+          vaultAta,
+          depositorAta: creatorTokenAccount.address,
+          feeVault,
+          feeStats,
+          mintFeeOverride,
+          autopayEscrow,
+          mint,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([creator])
+        .rpc();
+
+      await confirm(provider.connection, tx);
+
+      const vaultAfter = await program.account.vault.fetch(vault);
+      const escrowBalanceAfter = await provider.connection.getBalance(
+        autopayEscrow
+      );
+      const additionalPayouts = vaultAfter.totalNumberOfPayouts.sub(
+        vaultBefore.totalNumberOfPayouts
+      );
+
+      expect(additionalPayouts.toNumber()).toBeGreaterThan(0);
+      expect(escrowBalanceAfter - escrowBalanceBefore).toStrictEqual(
+        escrowAccount.lamportsPerDisburse.mul(additionalPayouts).toNumber()
+      );
+    });
+  });
 });
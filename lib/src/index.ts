@@ -8,3 +8,4 @@ export * from "./lib/sleep";
 export * from "./lib/optimizeTransaction";
 export * from "./lib/getValhallaConfig";
 export * from "./lib/getVaultByIndentifier";
+export * from "./lib/getVaultOptionalAccounts";
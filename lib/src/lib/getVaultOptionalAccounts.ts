@@ -0,0 +1,35 @@
+import * as anchor from "@coral-xyz/anchor";
+
+import { PROGRAM_ID, Vault, VestingMode } from "./program";
+
+import { PublicKey } from "@solana/web3.js";
+import { ValhallaVault } from "./models";
+import { getPDAs } from "./getPDAs";
+
+export interface ValhallaVaultOptionalAccounts {
+  schedule?: PublicKey;
+  beneficiaries?: PublicKey;
+  autopayEscrow?: PublicKey;
+}
+
+/**
+ * Returns the optional accounts that `disburse`, `cancel` and `close` need for the vault: the
+ * schedule of scheduled vaults, the beneficiaries of split vaults and the escrow of autopay vaults.
+ */
+export function getVaultOptionalAccounts(
+  vault: Vault | ValhallaVault
+): ValhallaVaultOptionalAccounts {
+  const { schedule, beneficiaries, autopayEscrow } = getPDAs(
+    PROGRAM_ID,
+    new anchor.BN(vault.identifier),
+    vault.creator,
+    vault.mint
+  );
+
+  return {
+    schedule:
+      vault.vestingMode === VestingMode.Scheduled ? schedule : undefined,
+    beneficiaries: vault.isSplit ? beneficiaries : undefined,
+    autopayEscrow: vault.autopay ? autopayEscrow : undefined,
+  };
+}
@@ -10,7 +10,7 @@ import {
 } from "@solana/spl-token";
 import { Connection, PublicKey } from "@solana/web3.js";
 
-import { Authority } from "./program/types";
+import { Authority, VestingMode } from "./program/types";
 import { DasApiAsset } from "@metaplex-foundation/digital-asset-standard-api";
 import { PROGRAM_ID } from "./program";
 import { Vault } from "./program/accounts";
@@ -26,6 +26,8 @@ export class ValhallaVault {
   recipient: PublicKey;
   mint: PublicKey;
   autopay: boolean;
+  vestingMode: VestingMode;
+  isSplit: boolean;
   _totalVestingDuration: anchor.BN = new anchor.BN(0);
   _createdTimestamp: anchor.BN = new anchor.BN(0);
   _startDate: anchor.BN = new anchor.BN(0);
@@ -57,6 +59,8 @@ export class ValhallaVault {
     this.recipient = obj.recipient;
     this.mint = obj.mint;
     this.autopay = obj.autopay;
+    this.vestingMode = obj.vestingMode;
+    this.isSplit = obj.isSplit;
     this._totalVestingDuration = new anchor.BN(obj.totalVestingDuration);
     this._createdTimestamp = new anchor.BN(obj.createdTimestamp);
     this._startDate = new anchor.BN(obj.startDate);
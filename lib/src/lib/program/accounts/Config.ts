@@ -8,6 +8,11 @@
 import * as web3 from '@solana/web3.js'
 import * as beet from '@metaplex-foundation/beet'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
+import {
+  MintExtensionPolicy,
+  mintExtensionPolicyBeet,
+} from '../types/MintExtensionPolicy'
+import { FeeDiscountTier, feeDiscountTierBeet } from '../types/FeeDiscountTier'
 
 /**
  * Arguments used to create {@link Config}
@@ -23,6 +28,16 @@ export type ConfigArgs = {
   autopayMultiplier: beet.bignum
   tokenFeeBasisPoints: beet.bignum
   governanceTokenAmount: beet.bignum
+  pendingAdmin: web3.PublicKey
+  configChangeDelay: beet.bignum
+  mintExtensionPolicy: MintExtensionPolicy
+  mintExtensions: beet.bignum
+  allowFreezeAuthority: boolean
+  paused: boolean
+  pauseFlags: number
+  feeDiscountTiers: FeeDiscountTier[]
+  crankRewardLamports: beet.bignum
+  crankRewardGovernanceTokenAmount: beet.bignum
 }
 
 export const configDiscriminator = [155, 12, 170, 224, 30, 250, 204, 130]
@@ -42,7 +57,17 @@ export class Config implements ConfigArgs {
     readonly devFee: beet.bignum,
     readonly autopayMultiplier: beet.bignum,
     readonly tokenFeeBasisPoints: beet.bignum,
-    readonly governanceTokenAmount: beet.bignum
+    readonly governanceTokenAmount: beet.bignum,
+    readonly pendingAdmin: web3.PublicKey,
+    readonly configChangeDelay: beet.bignum,
+    readonly mintExtensionPolicy: MintExtensionPolicy,
+    readonly mintExtensions: beet.bignum,
+    readonly allowFreezeAuthority: boolean,
+    readonly paused: boolean,
+    readonly pauseFlags: number,
+    readonly feeDiscountTiers: FeeDiscountTier[],
+    readonly crankRewardLamports: beet.bignum,
+    readonly crankRewardGovernanceTokenAmount: beet.bignum
   ) {}
 
   /**
@@ -57,7 +82,17 @@ export class Config implements ConfigArgs {
       args.devFee,
       args.autopayMultiplier,
       args.tokenFeeBasisPoints,
-      args.governanceTokenAmount
+      args.governanceTokenAmount,
+      args.pendingAdmin,
+      args.configChangeDelay,
+      args.mintExtensionPolicy,
+      args.mintExtensions,
+      args.allowFreezeAuthority,
+      args.paused,
+      args.pauseFlags,
+      args.feeDiscountTiers,
+      args.crankRewardLamports,
+      args.crankRewardGovernanceTokenAmount
     )
   }
 
@@ -128,36 +163,38 @@ export class Config implements ConfigArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Config}
+   * {@link Config} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return configBeet.byteSize
+  static byteSize(args: ConfigArgs) {
+    const instance = Config.fromArgs(args)
+    return configBeet.toFixedFromValue({
+      accountDiscriminator: configDiscriminator,
+      ...instance,
+    }).byteSize
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Config} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: ConfigArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment
   ): Promise<number> {
     return connection.getMinimumBalanceForRentExemption(
-      Config.byteSize,
+      Config.byteSize(args),
       commitment
     )
   }
 
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link Config} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === Config.byteSize
-  }
-
   /**
    * Returns a readable version of {@link Config} properties
    * and can be used to convert to JSON and/or logging
@@ -212,6 +249,57 @@ export class Config implements ConfigArgs {
         }
         return x
       })(),
+      pendingAdmin: this.pendingAdmin.toBase58(),
+      configChangeDelay: (() => {
+        const x = <{ toNumber: () => number }>this.configChangeDelay
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      mintExtensionPolicy:
+        'MintExtensionPolicy.' + MintExtensionPolicy[this.mintExtensionPolicy],
+      mintExtensions: (() => {
+        const x = <{ toNumber: () => number }>this.mintExtensions
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      allowFreezeAuthority: this.allowFreezeAuthority,
+      paused: this.paused,
+      pauseFlags: this.pauseFlags,
+      feeDiscountTiers: this.feeDiscountTiers,
+      crankRewardLamports: (() => {
+        const x = <{ toNumber: () => number }>this.crankRewardLamports
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      crankRewardGovernanceTokenAmount: (() => {
+        const x = <{ toNumber: () => number }>this.crankRewardGovernanceTokenAmount
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
     }
   }
 }
@@ -220,7 +308,7 @@ export class Config implements ConfigArgs {
  * @category Accounts
  * @category generated
  */
-export const configBeet = new beet.BeetStruct<
+export const configBeet = new beet.FixableBeetStruct<
   Config,
   ConfigArgs & {
     accountDiscriminator: number[] /* size: 8 */
@@ -236,6 +324,16 @@ export const configBeet = new beet.BeetStruct<
     ['autopayMultiplier', beet.u64],
     ['tokenFeeBasisPoints', beet.u64],
     ['governanceTokenAmount', beet.u64],
+    ['pendingAdmin', beetSolana.publicKey],
+    ['configChangeDelay', beet.u64],
+    ['mintExtensionPolicy', mintExtensionPolicyBeet],
+    ['mintExtensions', beet.u64],
+    ['allowFreezeAuthority', beet.bool],
+    ['paused', beet.bool],
+    ['pauseFlags', beet.u8],
+    ['feeDiscountTiers', beet.array(feeDiscountTierBeet)],
+    ['crankRewardLamports', beet.u64],
+    ['crankRewardGovernanceTokenAmount', beet.u64],
   ],
   Config.fromArgs,
   'Config'
@@ -9,6 +9,7 @@ import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
 import * as beetSolana from '@metaplex-foundation/beet-solana'
 import { Authority, authorityBeet } from '../types/Authority'
+import { VestingMode, vestingModeBeet } from '../types/VestingMode'
 
 /**
  * Arguments used to create {@link Vault}
@@ -32,6 +33,15 @@ export type VaultArgs = {
   cancelAuthority: Authority
   autopay: boolean
   tokenAccountBump: number
+  cliffDate: beet.bignum
+  cliffAmount: beet.bignum
+  isCliffPaymentDisbursed: boolean
+  totalWithdrawn: beet.bignum
+  vestingMode: VestingMode
+  transferAuthority: Authority
+  allowPublicDeposits: boolean
+  isSplit: boolean
+  isNative: boolean
 }
 
 export const vaultDiscriminator = [211, 8, 232, 43, 2, 152, 117, 119]
@@ -59,7 +69,16 @@ export class Vault implements VaultArgs {
     readonly numberOfPaymentsMade: beet.bignum,
     readonly cancelAuthority: Authority,
     readonly autopay: boolean,
-    readonly tokenAccountBump: number
+    readonly tokenAccountBump: number,
+    readonly cliffDate: beet.bignum,
+    readonly cliffAmount: beet.bignum,
+    readonly isCliffPaymentDisbursed: boolean,
+    readonly totalWithdrawn: beet.bignum,
+    readonly vestingMode: VestingMode,
+    readonly transferAuthority: Authority,
+    readonly allowPublicDeposits: boolean,
+    readonly isSplit: boolean,
+    readonly isNative: boolean
   ) {}
 
   /**
@@ -82,7 +101,16 @@ export class Vault implements VaultArgs {
       args.numberOfPaymentsMade,
       args.cancelAuthority,
       args.autopay,
-      args.tokenAccountBump
+      args.tokenAccountBump,
+      args.cliffDate,
+      args.cliffAmount,
+      args.isCliffPaymentDisbursed,
+      args.totalWithdrawn,
+      args.vestingMode,
+      args.transferAuthority,
+      args.allowPublicDeposits,
+      args.isSplit,
+      args.isNative
     )
   }
 
@@ -295,6 +323,45 @@ export class Vault implements VaultArgs {
       cancelAuthority: 'Authority.' + Authority[this.cancelAuthority],
       autopay: this.autopay,
       tokenAccountBump: this.tokenAccountBump,
+      cliffDate: (() => {
+        const x = <{ toNumber: () => number }>this.cliffDate
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      cliffAmount: (() => {
+        const x = <{ toNumber: () => number }>this.cliffAmount
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      isCliffPaymentDisbursed: this.isCliffPaymentDisbursed,
+      totalWithdrawn: (() => {
+        const x = <{ toNumber: () => number }>this.totalWithdrawn
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber()
+          } catch (_) {
+            return x
+          }
+        }
+        return x
+      })(),
+      vestingMode: 'VestingMode.' + VestingMode[this.vestingMode],
+      transferAuthority: 'Authority.' + Authority[this.transferAuthority],
+      allowPublicDeposits: this.allowPublicDeposits,
+      isSplit: this.isSplit,
+      isNative: this.isNative,
     }
   }
 }
@@ -327,6 +394,15 @@ export const vaultBeet = new beet.BeetStruct<
     ['cancelAuthority', authorityBeet],
     ['autopay', beet.bool],
     ['tokenAccountBump', beet.u8],
+    ['cliffDate', beet.u64],
+    ['cliffAmount', beet.u64],
+    ['isCliffPaymentDisbursed', beet.bool],
+    ['totalWithdrawn', beet.u64],
+    ['vestingMode', vestingModeBeet],
+    ['transferAuthority', authorityBeet],
+    ['allowPublicDeposits', beet.bool],
+    ['isSplit', beet.bool],
+    ['isNative', beet.bool],
   ],
   Vault.fromArgs,
   'Vault'
@@ -1,7 +1,28 @@
+export * from './AutopayEscrow'
+export * from './Beneficiaries'
 export * from './Config'
+export * from './FeeStats'
+export * from './MintFeeOverride'
+export * from './PendingConfigChange'
+export * from './Schedule'
 export * from './Vault'
 
+import { AutopayEscrow } from './AutopayEscrow'
+import { Beneficiaries } from './Beneficiaries'
 import { Config } from './Config'
+import { FeeStats } from './FeeStats'
+import { MintFeeOverride } from './MintFeeOverride'
+import { PendingConfigChange } from './PendingConfigChange'
+import { Schedule } from './Schedule'
 import { Vault } from './Vault'
 
-export const accountProviders = { Config, Vault }
+export const accountProviders = {
+  AutopayEscrow,
+  Beneficiaries,
+  Config,
+  FeeStats,
+  MintFeeOverride,
+  PendingConfigChange,
+  Schedule,
+  Vault,
+}
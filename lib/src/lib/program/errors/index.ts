@@ -186,6 +186,379 @@ createErrorFromNameLookup.set(
   () => new FeePaymentFailedError()
 )
 
+/**
+ * InvalidCliff: 'Cliff is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCliffError extends Error {
+  readonly code: number = 0x1778
+  readonly name: string = 'InvalidCliff'
+  constructor() {
+    super('Cliff is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCliffError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1778, () => new InvalidCliffError())
+createErrorFromNameLookup.set('InvalidCliff', () => new InvalidCliffError())
+
+/**
+ * InvalidSchedule: 'Schedule is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidScheduleError extends Error {
+  readonly code: number = 0x1779
+  readonly name: string = 'InvalidSchedule'
+  constructor() {
+    super('Schedule is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidScheduleError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1779, () => new InvalidScheduleError())
+createErrorFromNameLookup.set(
+  'InvalidSchedule',
+  () => new InvalidScheduleError()
+)
+
+/**
+ * InvalidBatch: 'Batch is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBatchError extends Error {
+  readonly code: number = 0x177a
+  readonly name: string = 'InvalidBatch'
+  constructor() {
+    super('Batch is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBatchError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177a, () => new InvalidBatchError())
+createErrorFromNameLookup.set('InvalidBatch', () => new InvalidBatchError())
+
+/**
+ * InvalidDeposit: 'Deposit is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDepositError extends Error {
+  readonly code: number = 0x177b
+  readonly name: string = 'InvalidDeposit'
+  constructor() {
+    super('Deposit is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDepositError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177b, () => new InvalidDepositError())
+createErrorFromNameLookup.set('InvalidDeposit', () => new InvalidDepositError())
+
+/**
+ * InvalidBeneficiaries: 'Beneficiaries are invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBeneficiariesError extends Error {
+  readonly code: number = 0x177c
+  readonly name: string = 'InvalidBeneficiaries'
+  constructor() {
+    super('Beneficiaries are invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBeneficiariesError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177c, () => new InvalidBeneficiariesError())
+createErrorFromNameLookup.set(
+  'InvalidBeneficiaries',
+  () => new InvalidBeneficiariesError()
+)
+
+/**
+ * InvalidMintExtensions: 'Mint extensions are invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidMintExtensionsError extends Error {
+  readonly code: number = 0x177d
+  readonly name: string = 'InvalidMintExtensions'
+  constructor() {
+    super('Mint extensions are invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidMintExtensionsError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177d, () => new InvalidMintExtensionsError())
+createErrorFromNameLookup.set(
+  'InvalidMintExtensions',
+  () => new InvalidMintExtensionsError()
+)
+
+/**
+ * InvalidVaultType: 'Vault type is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidVaultTypeError extends Error {
+  readonly code: number = 0x177e
+  readonly name: string = 'InvalidVaultType'
+  constructor() {
+    super('Vault type is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidVaultTypeError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177e, () => new InvalidVaultTypeError())
+createErrorFromNameLookup.set(
+  'InvalidVaultType',
+  () => new InvalidVaultTypeError()
+)
+
+/**
+ * MathOverflow: 'Math overflow!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MathOverflowError extends Error {
+  readonly code: number = 0x177f
+  readonly name: string = 'MathOverflow'
+  constructor() {
+    super('Math overflow!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MathOverflowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177f, () => new MathOverflowError())
+createErrorFromNameLookup.set('MathOverflow', () => new MathOverflowError())
+
+/**
+ * InvalidPayoutInterval: 'Payout interval is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPayoutIntervalError extends Error {
+  readonly code: number = 0x1780
+  readonly name: string = 'InvalidPayoutInterval'
+  constructor() {
+    super('Payout interval is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPayoutIntervalError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1780, () => new InvalidPayoutIntervalError())
+createErrorFromNameLookup.set(
+  'InvalidPayoutInterval',
+  () => new InvalidPayoutIntervalError()
+)
+
+/**
+ * InvalidStartDate: 'Start date is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidStartDateError extends Error {
+  readonly code: number = 0x1781
+  readonly name: string = 'InvalidStartDate'
+  constructor() {
+    super('Start date is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidStartDateError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1781, () => new InvalidStartDateError())
+createErrorFromNameLookup.set(
+  'InvalidStartDate',
+  () => new InvalidStartDateError()
+)
+
+/**
+ * InvalidAmount: 'Amount is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAmountError extends Error {
+  readonly code: number = 0x1782
+  readonly name: string = 'InvalidAmount'
+  constructor() {
+    super('Amount is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAmountError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1782, () => new InvalidAmountError())
+createErrorFromNameLookup.set('InvalidAmount', () => new InvalidAmountError())
+
+/**
+ * ConfigChangeLocked: 'Config change is locked!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ConfigChangeLockedError extends Error {
+  readonly code: number = 0x1783
+  readonly name: string = 'ConfigChangeLocked'
+  constructor() {
+    super('Config change is locked!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ConfigChangeLockedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1783, () => new ConfigChangeLockedError())
+createErrorFromNameLookup.set(
+  'ConfigChangeLocked',
+  () => new ConfigChangeLockedError()
+)
+
+/**
+ * Paused: 'The program is paused!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class PausedError extends Error {
+  readonly code: number = 0x1784
+  readonly name: string = 'Paused'
+  constructor() {
+    super('The program is paused!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, PausedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1784, () => new PausedError())
+createErrorFromNameLookup.set('Paused', () => new PausedError())
+
+/**
+ * InvalidFeeDiscountTiers: 'Fee discount tiers are invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidFeeDiscountTiersError extends Error {
+  readonly code: number = 0x1785
+  readonly name: string = 'InvalidFeeDiscountTiers'
+  constructor() {
+    super('Fee discount tiers are invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidFeeDiscountTiersError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1785, () => new InvalidFeeDiscountTiersError())
+createErrorFromNameLookup.set(
+  'InvalidFeeDiscountTiers',
+  () => new InvalidFeeDiscountTiersError()
+)
+
+/**
+ * InvalidAutopayEscrow: 'Autopay escrow is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidAutopayEscrowError extends Error {
+  readonly code: number = 0x1786
+  readonly name: string = 'InvalidAutopayEscrow'
+  constructor() {
+    super('Autopay escrow is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidAutopayEscrowError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1786, () => new InvalidAutopayEscrowError())
+createErrorFromNameLookup.set(
+  'InvalidAutopayEscrow',
+  () => new InvalidAutopayEscrowError()
+)
+
+/**
+ * AlreadyMigrated: 'The account has already been migrated!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AlreadyMigratedError extends Error {
+  readonly code: number = 0x1787
+  readonly name: string = 'AlreadyMigrated'
+  constructor() {
+    super('The account has already been migrated!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AlreadyMigratedError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1787, () => new AlreadyMigratedError())
+createErrorFromNameLookup.set(
+  'AlreadyMigrated',
+  () => new AlreadyMigratedError()
+)
+
+/**
+ * InvalidTransferAuthority: 'Transfer authority is invalid!'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTransferAuthorityError extends Error {
+  readonly code: number = 0x1788
+  readonly name: string = 'InvalidTransferAuthority'
+  constructor() {
+    super('Transfer authority is invalid!')
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTransferAuthorityError)
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1788, () => new InvalidTransferAuthorityError())
+createErrorFromNameLookup.set(
+  'InvalidTransferAuthority',
+  () => new InvalidTransferAuthorityError()
+)
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
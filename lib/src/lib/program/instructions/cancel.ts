@@ -28,8 +28,12 @@ export const cancelStruct = new beet.BeetArgsStruct<{
  * @property [_writable_] recipient
  * @property [_writable_] vault
  * @property [_writable_] vaultAta
+ * @property [_writable_] schedule (optional)
+ * @property [_writable_] beneficiaries (optional)
+ * @property [_writable_] autopayEscrow (optional)
  * @property [_writable_] creatorAta
- * @property [] mint
+ * @property [_writable_] recipientAta
+ * @property [_writable_] mint
  * @property [] associatedTokenProgram
  * @category Instructions
  * @category Cancel
@@ -41,7 +45,11 @@ export type CancelInstructionAccounts = {
   recipient: web3.PublicKey
   vault: web3.PublicKey
   vaultAta: web3.PublicKey
+  schedule?: web3.PublicKey
+  beneficiaries?: web3.PublicKey
+  autopayEscrow?: web3.PublicKey
   creatorAta: web3.PublicKey
+  recipientAta: web3.PublicKey
   mint: web3.PublicKey
   tokenProgram?: web3.PublicKey
   associatedTokenProgram: web3.PublicKey
@@ -93,14 +101,34 @@ export function createCancelInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.schedule ?? programId,
+      isWritable: accounts.schedule != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.beneficiaries ?? programId,
+      isWritable: accounts.beneficiaries != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.autopayEscrow ?? programId,
+      isWritable: accounts.autopayEscrow != null,
+      isSigner: false,
+    },
     {
       pubkey: accounts.creatorAta,
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.recipientAta,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.mint,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
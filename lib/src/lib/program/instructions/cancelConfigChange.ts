@@ -10,49 +10,49 @@ import * as web3 from '@solana/web3.js'
 
 /**
  * @category Instructions
- * @category UpdateDaoTreasury
+ * @category CancelConfigChange
  * @category generated
  */
-export const updateDaoTreasuryStruct = new beet.BeetArgsStruct<{
+export const cancelConfigChangeStruct = new beet.BeetArgsStruct<{
   instructionDiscriminator: number[] /* size: 8 */
 }>(
   [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
-  'UpdateDaoTreasuryInstructionArgs'
+  'CancelConfigChangeInstructionArgs'
 )
 /**
- * Accounts required by the _updateDaoTreasury_ instruction
+ * Accounts required by the _cancelConfigChange_ instruction
  *
  * @property [_writable_, **signer**] admin
- * @property [] newDaoTreasury
- * @property [_writable_] config
+ * @property [] config
+ * @property [_writable_] pendingConfigChange
  * @category Instructions
- * @category UpdateDaoTreasury
+ * @category CancelConfigChange
  * @category generated
  */
-export type UpdateDaoTreasuryInstructionAccounts = {
+export type CancelConfigChangeInstructionAccounts = {
   admin: web3.PublicKey
-  newDaoTreasury: web3.PublicKey
   config: web3.PublicKey
+  pendingConfigChange: web3.PublicKey
 }
 
-export const updateDaoTreasuryInstructionDiscriminator = [
-  50, 210, 233, 46, 9, 38, 87, 196,
+export const cancelConfigChangeInstructionDiscriminator = [
+  222, 114, 136, 167, 183, 86, 61, 158,
 ]
 
 /**
- * Creates a _UpdateDaoTreasury_ instruction.
+ * Creates a _CancelConfigChange_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
  * @category Instructions
- * @category UpdateDaoTreasury
+ * @category CancelConfigChange
  * @category generated
  */
-export function createUpdateDaoTreasuryInstruction(
-  accounts: UpdateDaoTreasuryInstructionAccounts,
+export function createCancelConfigChangeInstruction(
+  accounts: CancelConfigChangeInstructionAccounts,
   programId = new web3.PublicKey('BBczhggWEH5Y5zZNJjgLDWhZhfaSjxm1TcLpYhB79RgY')
 ) {
-  const [data] = updateDaoTreasuryStruct.serialize({
-    instructionDiscriminator: updateDaoTreasuryInstructionDiscriminator,
+  const [data] = cancelConfigChangeStruct.serialize({
+    instructionDiscriminator: cancelConfigChangeInstructionDiscriminator,
   })
   const keys: web3.AccountMeta[] = [
     {
@@ -61,12 +61,12 @@ export function createUpdateDaoTreasuryInstruction(
       isSigner: true,
     },
     {
-      pubkey: accounts.newDaoTreasury,
+      pubkey: accounts.config,
       isWritable: false,
       isSigner: false,
     },
     {
-      pubkey: accounts.config,
+      pubkey: accounts.pendingConfigChange,
       isWritable: true,
       isSigner: false,
     },
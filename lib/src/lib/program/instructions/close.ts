@@ -26,7 +26,10 @@ export const closeStruct = new beet.BeetArgsStruct<{
  * @property [_writable_, **signer**] creator
  * @property [_writable_] vault
  * @property [_writable_] vaultAta
- * @property [] mint
+ * @property [_writable_] schedule (optional)
+ * @property [_writable_] beneficiaries (optional)
+ * @property [_writable_] autopayEscrow (optional)
+ * @property [_writable_] mint
  * @category Instructions
  * @category Close
  * @category generated
@@ -35,6 +38,9 @@ export type CloseInstructionAccounts = {
   creator: web3.PublicKey
   vault: web3.PublicKey
   vaultAta: web3.PublicKey
+  schedule?: web3.PublicKey
+  beneficiaries?: web3.PublicKey
+  autopayEscrow?: web3.PublicKey
   mint: web3.PublicKey
   tokenProgram?: web3.PublicKey
 }
@@ -74,9 +80,24 @@ export function createCloseInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.schedule ?? programId,
+      isWritable: accounts.schedule != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.beneficiaries ?? programId,
+      isWritable: accounts.beneficiaries != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.autopayEscrow ?? programId,
+      isWritable: accounts.autopayEscrow != null,
+      isSigner: false,
+    },
     {
       pubkey: accounts.mint,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -8,6 +8,7 @@
 import * as splToken from '@solana/spl-token'
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import { VestingMode, vestingModeBeet } from '../types/VestingMode'
 import { Authority, authorityBeet } from '../types/Authority'
 
 /**
@@ -22,8 +23,13 @@ export type CreateInstructionArgs = {
   totalVestingDuration: beet.bignum
   startDate: beet.bignum
   payoutInterval: beet.bignum
+  cliffDate: beet.bignum
+  cliffAmount: beet.bignum
+  vestingMode: VestingMode
   cancelAuthority: Authority
+  transferAuthority: Authority
   autopay: boolean
+  allowPublicDeposits: boolean
 }
 /**
  * @category Instructions
@@ -43,8 +49,13 @@ export const createStruct = new beet.BeetArgsStruct<
     ['totalVestingDuration', beet.u64],
     ['startDate', beet.u64],
     ['payoutInterval', beet.u64],
+    ['cliffDate', beet.u64],
+    ['cliffAmount', beet.u64],
+    ['vestingMode', vestingModeBeet],
     ['cancelAuthority', authorityBeet],
+    ['transferAuthority', authorityBeet],
     ['autopay', beet.bool],
+    ['allowPublicDeposits', beet.bool],
   ],
   'CreateInstructionArgs'
 )
@@ -53,12 +64,13 @@ export const createStruct = new beet.BeetArgsStruct<
  *
  * @property [_writable_, **signer**] creator
  * @property [_writable_] recipient
- * @property [_writable_] devTreasury
- * @property [_writable_] daoTreasury
  * @property [] config
  * @property [_writable_] vault
  * @property [_writable_] vaultAta
- * @property [_writable_] daoTreasuryAta
+ * @property [_writable_] feeVault
+ * @property [_writable_] feeStats
+ * @property [] mintFeeOverride
+ * @property [_writable_] autopayEscrow (optional)
  * @property [_writable_] creatorAta
  * @property [_writable_] creatorGovernanceAta
  * @property [] mint
@@ -72,12 +84,13 @@ export const createStruct = new beet.BeetArgsStruct<
 export type CreateInstructionAccounts = {
   creator: web3.PublicKey
   recipient: web3.PublicKey
-  devTreasury: web3.PublicKey
-  daoTreasury: web3.PublicKey
   config: web3.PublicKey
   vault: web3.PublicKey
   vaultAta: web3.PublicKey
-  daoTreasuryAta: web3.PublicKey
+  feeVault: web3.PublicKey
+  feeStats: web3.PublicKey
+  mintFeeOverride: web3.PublicKey
+  autopayEscrow?: web3.PublicKey
   creatorAta: web3.PublicKey
   creatorGovernanceAta: web3.PublicKey
   mint: web3.PublicKey
@@ -121,33 +134,38 @@ export function createCreateInstruction(
       isSigner: false,
     },
     {
-      pubkey: accounts.devTreasury,
-      isWritable: true,
+      pubkey: accounts.config,
+      isWritable: false,
       isSigner: false,
     },
     {
-      pubkey: accounts.daoTreasury,
+      pubkey: accounts.vault,
       isWritable: true,
       isSigner: false,
     },
     {
-      pubkey: accounts.config,
-      isWritable: false,
+      pubkey: accounts.vaultAta,
+      isWritable: true,
       isSigner: false,
     },
     {
-      pubkey: accounts.vault,
+      pubkey: accounts.feeVault,
       isWritable: true,
       isSigner: false,
     },
     {
-      pubkey: accounts.vaultAta,
+      pubkey: accounts.feeStats,
       isWritable: true,
       isSigner: false,
     },
     {
-      pubkey: accounts.daoTreasuryAta,
-      isWritable: true,
+      pubkey: accounts.mintFeeOverride,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.autopayEscrow ?? programId,
+      isWritable: accounts.autopayEscrow != null,
       isSigner: false,
     },
     {
@@ -23,6 +23,7 @@ export type CreateConfigInstructionArgs = {
   autopayMultiplier: beet.bignum
   tokenFeeBasisPoints: beet.bignum
   governanceTokenAmount: beet.bignum
+  configChangeDelay: beet.bignum
 }
 /**
  * @category Instructions
@@ -44,6 +45,7 @@ export const createConfigStruct = new beet.FixableBeetArgsStruct<
     ['autopayMultiplier', beet.u64],
     ['tokenFeeBasisPoints', beet.u64],
     ['governanceTokenAmount', beet.u64],
+    ['configChangeDelay', beet.u64],
   ],
   'CreateConfigInstructionArgs'
 )
@@ -30,6 +30,9 @@ export const disburseStruct = new beet.BeetArgsStruct<{
  * @property [] config
  * @property [_writable_] vault
  * @property [_writable_] vaultAta
+ * @property [] schedule (optional)
+ * @property [] beneficiaries (optional)
+ * @property [_writable_] autopayEscrow (optional)
  * @property [_writable_] signerGovernanceAta
  * @property [_writable_] creatorGovernanceAta
  * @property [_writable_] recipientAta
@@ -49,6 +52,9 @@ export type DisburseInstructionAccounts = {
   config: web3.PublicKey
   vault: web3.PublicKey
   vaultAta: web3.PublicKey
+  schedule?: web3.PublicKey
+  beneficiaries?: web3.PublicKey
+  autopayEscrow?: web3.PublicKey
   signerGovernanceAta: web3.PublicKey
   creatorGovernanceAta: web3.PublicKey
   recipientAta: web3.PublicKey
@@ -115,6 +121,21 @@ export function createDisburseInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.schedule ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.beneficiaries ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.autopayEscrow ?? programId,
+      isWritable: accounts.autopayEscrow != null,
+      isSigner: false,
+    },
     {
       pubkey: accounts.signerGovernanceAta,
       isWritable: true,
@@ -1,11 +1,32 @@
+export * from './acceptAdmin'
 export * from './cancel'
+export * from './cancelConfigChange'
+export * from './cancelSol'
 export * from './close'
+export * from './closeMintFeeOverride'
+export * from './closeSol'
 export * from './create'
+export * from './createBatch'
+export * from './createBeneficiaries'
 export * from './createConfig'
+export * from './createSol'
+export * from './createSolWithLamports'
+export * from './createWithBaseUnits'
+export * from './createWithSchedule'
+export * from './deposit'
 export * from './disburse'
+export * from './disburseSol'
+export * from './executeConfigChange'
+export * from './getClaimable'
+export * from './migrateConfig'
+export * from './migrateVault'
 export * from './mintGovernanceTokens'
-export * from './updateAdmin'
-export * from './updateDaoTreasury'
-export * from './updateDevFee'
-export * from './updateGovernanceTokenAmount'
-export * from './updateTokenFeeBasisPoints'
+export * from './proposeAdmin'
+export * from './queueConfigChange'
+export * from './setMintFeeOverride'
+export * from './transferRecipient'
+export * from './updateFeeDiscountTiers'
+export * from './updateMintExtensionPolicy'
+export * from './updatePause'
+export * from './withdrawFees'
+export * from './withdrawSolFees'
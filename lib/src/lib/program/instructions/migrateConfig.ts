@@ -10,63 +10,64 @@ import * as web3 from '@solana/web3.js'
 
 /**
  * @category Instructions
- * @category UpdateTokenFeeBasisPoints
+ * @category MigrateConfig
  * @category generated
  */
-export type UpdateTokenFeeBasisPointsInstructionArgs = {
-  tokenFeeBasisPoints: beet.bignum
+export type MigrateConfigInstructionArgs = {
+  configChangeDelay: beet.bignum
 }
 /**
  * @category Instructions
- * @category UpdateTokenFeeBasisPoints
+ * @category MigrateConfig
  * @category generated
  */
-export const updateTokenFeeBasisPointsStruct = new beet.BeetArgsStruct<
-  UpdateTokenFeeBasisPointsInstructionArgs & {
+export const migrateConfigStruct = new beet.BeetArgsStruct<
+  MigrateConfigInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */
   }
 >(
   [
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
-    ['tokenFeeBasisPoints', beet.u64],
+    ['configChangeDelay', beet.u64],
   ],
-  'UpdateTokenFeeBasisPointsInstructionArgs'
+  'MigrateConfigInstructionArgs'
 )
 /**
- * Accounts required by the _updateTokenFeeBasisPoints_ instruction
+ * Accounts required by the _migrateConfig_ instruction
  *
  * @property [_writable_, **signer**] admin
  * @property [_writable_] config
  * @category Instructions
- * @category UpdateTokenFeeBasisPoints
+ * @category MigrateConfig
  * @category generated
  */
-export type UpdateTokenFeeBasisPointsInstructionAccounts = {
+export type MigrateConfigInstructionAccounts = {
   admin: web3.PublicKey
   config: web3.PublicKey
+  systemProgram?: web3.PublicKey
 }
 
-export const updateTokenFeeBasisPointsInstructionDiscriminator = [
-  242, 207, 78, 61, 81, 37, 216, 94,
+export const migrateConfigInstructionDiscriminator = [
+  92, 131, 58, 105, 210, 154, 224, 193,
 ]
 
 /**
- * Creates a _UpdateTokenFeeBasisPoints_ instruction.
+ * Creates a _MigrateConfig_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
  * @category Instructions
- * @category UpdateTokenFeeBasisPoints
+ * @category MigrateConfig
  * @category generated
  */
-export function createUpdateTokenFeeBasisPointsInstruction(
-  accounts: UpdateTokenFeeBasisPointsInstructionAccounts,
-  args: UpdateTokenFeeBasisPointsInstructionArgs,
+export function createMigrateConfigInstruction(
+  accounts: MigrateConfigInstructionAccounts,
+  args: MigrateConfigInstructionArgs,
   programId = new web3.PublicKey('BBczhggWEH5Y5zZNJjgLDWhZhfaSjxm1TcLpYhB79RgY')
 ) {
-  const [data] = updateTokenFeeBasisPointsStruct.serialize({
-    instructionDiscriminator: updateTokenFeeBasisPointsInstructionDiscriminator,
+  const [data] = migrateConfigStruct.serialize({
+    instructionDiscriminator: migrateConfigInstructionDiscriminator,
     ...args,
   })
   const keys: web3.AccountMeta[] = [
@@ -80,6 +81,11 @@ export function createUpdateTokenFeeBasisPointsInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
   ]
 
   const ix = new web3.TransactionInstruction({
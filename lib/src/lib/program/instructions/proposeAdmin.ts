@@ -10,49 +10,49 @@ import * as web3 from '@solana/web3.js'
 
 /**
  * @category Instructions
- * @category UpdateAdmin
+ * @category ProposeAdmin
  * @category generated
  */
-export const updateAdminStruct = new beet.BeetArgsStruct<{
+export const proposeAdminStruct = new beet.BeetArgsStruct<{
   instructionDiscriminator: number[] /* size: 8 */
 }>(
   [['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)]],
-  'UpdateAdminInstructionArgs'
+  'ProposeAdminInstructionArgs'
 )
 /**
- * Accounts required by the _updateAdmin_ instruction
+ * Accounts required by the _proposeAdmin_ instruction
  *
  * @property [_writable_, **signer**] admin
  * @property [] newAdmin
  * @property [_writable_] config
  * @category Instructions
- * @category UpdateAdmin
+ * @category ProposeAdmin
  * @category generated
  */
-export type UpdateAdminInstructionAccounts = {
+export type ProposeAdminInstructionAccounts = {
   admin: web3.PublicKey
   newAdmin: web3.PublicKey
   config: web3.PublicKey
 }
 
-export const updateAdminInstructionDiscriminator = [
-  161, 176, 40, 213, 60, 184, 179, 228,
+export const proposeAdminInstructionDiscriminator = [
+  121, 214, 199, 212, 87, 39, 117, 234,
 ]
 
 /**
- * Creates a _UpdateAdmin_ instruction.
+ * Creates a _ProposeAdmin_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
  * @category Instructions
- * @category UpdateAdmin
+ * @category ProposeAdmin
  * @category generated
  */
-export function createUpdateAdminInstruction(
-  accounts: UpdateAdminInstructionAccounts,
+export function createProposeAdminInstruction(
+  accounts: ProposeAdminInstructionAccounts,
   programId = new web3.PublicKey('BBczhggWEH5Y5zZNJjgLDWhZhfaSjxm1TcLpYhB79RgY')
 ) {
-  const [data] = updateAdminStruct.serialize({
-    instructionDiscriminator: updateAdminInstructionDiscriminator,
+  const [data] = proposeAdminStruct.serialize({
+    instructionDiscriminator: proposeAdminInstructionDiscriminator,
   })
   const keys: web3.AccountMeta[] = [
     {
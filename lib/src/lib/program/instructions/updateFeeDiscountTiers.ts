@@ -7,67 +7,67 @@
 
 import * as beet from '@metaplex-foundation/beet'
 import * as web3 from '@solana/web3.js'
+import { FeeDiscountTier, feeDiscountTierBeet } from '../types/FeeDiscountTier'
 
 /**
  * @category Instructions
- * @category UpdateGovernanceTokenAmount
+ * @category UpdateFeeDiscountTiers
  * @category generated
  */
-export type UpdateGovernanceTokenAmountInstructionArgs = {
-  governanceTokenAmount: beet.bignum
+export type UpdateFeeDiscountTiersInstructionArgs = {
+  feeDiscountTiers: FeeDiscountTier[]
 }
 /**
  * @category Instructions
- * @category UpdateGovernanceTokenAmount
+ * @category UpdateFeeDiscountTiers
  * @category generated
  */
-export const updateGovernanceTokenAmountStruct = new beet.BeetArgsStruct<
-  UpdateGovernanceTokenAmountInstructionArgs & {
+export const updateFeeDiscountTiersStruct = new beet.FixableBeetArgsStruct<
+  UpdateFeeDiscountTiersInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */
   }
 >(
   [
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
-    ['governanceTokenAmount', beet.u64],
+    ['feeDiscountTiers', beet.array(feeDiscountTierBeet)],
   ],
-  'UpdateGovernanceTokenAmountInstructionArgs'
+  'UpdateFeeDiscountTiersInstructionArgs'
 )
 /**
- * Accounts required by the _updateGovernanceTokenAmount_ instruction
+ * Accounts required by the _updateFeeDiscountTiers_ instruction
  *
  * @property [_writable_, **signer**] admin
  * @property [_writable_] config
  * @category Instructions
- * @category UpdateGovernanceTokenAmount
+ * @category UpdateFeeDiscountTiers
  * @category generated
  */
-export type UpdateGovernanceTokenAmountInstructionAccounts = {
+export type UpdateFeeDiscountTiersInstructionAccounts = {
   admin: web3.PublicKey
   config: web3.PublicKey
 }
 
-export const updateGovernanceTokenAmountInstructionDiscriminator = [
-  87, 119, 105, 95, 233, 93, 222, 118,
+export const updateFeeDiscountTiersInstructionDiscriminator = [
+  150, 104, 9, 13, 53, 21, 126, 43,
 ]
 
 /**
- * Creates a _UpdateGovernanceTokenAmount_ instruction.
+ * Creates a _UpdateFeeDiscountTiers_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
  * @category Instructions
- * @category UpdateGovernanceTokenAmount
+ * @category UpdateFeeDiscountTiers
  * @category generated
  */
-export function createUpdateGovernanceTokenAmountInstruction(
-  accounts: UpdateGovernanceTokenAmountInstructionAccounts,
-  args: UpdateGovernanceTokenAmountInstructionArgs,
+export function createUpdateFeeDiscountTiersInstruction(
+  accounts: UpdateFeeDiscountTiersInstructionAccounts,
+  args: UpdateFeeDiscountTiersInstructionArgs,
   programId = new web3.PublicKey('BBczhggWEH5Y5zZNJjgLDWhZhfaSjxm1TcLpYhB79RgY')
 ) {
-  const [data] = updateGovernanceTokenAmountStruct.serialize({
-    instructionDiscriminator:
-      updateGovernanceTokenAmountInstructionDiscriminator,
+  const [data] = updateFeeDiscountTiersStruct.serialize({
+    instructionDiscriminator: updateFeeDiscountTiersInstructionDiscriminator,
     ...args,
   })
   const keys: web3.AccountMeta[] = [
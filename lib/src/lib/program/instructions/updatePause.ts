@@ -10,63 +10,65 @@ import * as web3 from '@solana/web3.js'
 
 /**
  * @category Instructions
- * @category UpdateDevFee
+ * @category UpdatePause
  * @category generated
  */
-export type UpdateDevFeeInstructionArgs = {
-  devFee: beet.bignum
+export type UpdatePauseInstructionArgs = {
+  paused: boolean
+  pauseFlags: number
 }
 /**
  * @category Instructions
- * @category UpdateDevFee
+ * @category UpdatePause
  * @category generated
  */
-export const updateDevFeeStruct = new beet.BeetArgsStruct<
-  UpdateDevFeeInstructionArgs & {
+export const updatePauseStruct = new beet.BeetArgsStruct<
+  UpdatePauseInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */
   }
 >(
   [
     ['instructionDiscriminator', beet.uniformFixedSizeArray(beet.u8, 8)],
-    ['devFee', beet.u64],
+    ['paused', beet.bool],
+    ['pauseFlags', beet.u8],
   ],
-  'UpdateDevFeeInstructionArgs'
+  'UpdatePauseInstructionArgs'
 )
 /**
- * Accounts required by the _updateDevFee_ instruction
+ * Accounts required by the _updatePause_ instruction
  *
  * @property [_writable_, **signer**] admin
  * @property [_writable_] config
  * @category Instructions
- * @category UpdateDevFee
+ * @category UpdatePause
  * @category generated
  */
-export type UpdateDevFeeInstructionAccounts = {
+export type UpdatePauseInstructionAccounts = {
   admin: web3.PublicKey
   config: web3.PublicKey
 }
 
-export const updateDevFeeInstructionDiscriminator = [
-  170, 152, 29, 116, 61, 77, 221, 81,
+export const updatePauseInstructionDiscriminator = [
+  6, 56, 103, 134, 181, 122, 69, 108,
 ]
 
 /**
- * Creates a _UpdateDevFee_ instruction.
+ * Creates a _UpdatePause_ instruction.
  *
  * @param accounts that will be accessed while the instruction is processed
  * @param args to provide as instruction data to the program
  *
  * @category Instructions
- * @category UpdateDevFee
+ * @category UpdatePause
  * @category generated
  */
-export function createUpdateDevFeeInstruction(
-  accounts: UpdateDevFeeInstructionAccounts,
-  args: UpdateDevFeeInstructionArgs,
+export function createUpdatePauseInstruction(
+  accounts: UpdatePauseInstructionAccounts,
+  args: UpdatePauseInstructionArgs,
   programId = new web3.PublicKey('BBczhggWEH5Y5zZNJjgLDWhZhfaSjxm1TcLpYhB79RgY')
 ) {
-  const [data] = updateDevFeeStruct.serialize({
-    instructionDiscriminator: updateDevFeeInstructionDiscriminator,
+  const [data] = updatePauseStruct.serialize({
+    instructionDiscriminator: updatePauseInstructionDiscriminator,
     ...args,
   })
   const keys: web3.AccountMeta[] = [
@@ -1 +1,10 @@
 export * from './Authority'
+export * from './BatchVault'
+export * from './Beneficiary'
+export * from './Claimable'
+export * from './ConfigChange'
+export * from './DepositMode'
+export * from './FeeDiscountTier'
+export * from './MintExtensionPolicy'
+export * from './Tranche'
+export * from './VestingMode'
@@ -14,6 +14,7 @@ import {
   getMintWithCorrectTokenProgram,
   getPDAs,
   getValhallaConfig,
+  getVaultOptionalAccounts,
 } from "@valhalla/lib";
 import { connection, provider } from "./network";
 
@@ -62,6 +63,7 @@ export const disburse = async (vault: Vault | ValhallaVault) => {
     config: key,
     vault: vaultKey,
     vaultAta,
+    ...getVaultOptionalAccounts(vault),
     signerGovernanceAta,
     recipientAta,
     mint: vault.mint,
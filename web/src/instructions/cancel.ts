@@ -6,6 +6,7 @@ import {
   CancelInstructionAccounts,
   ValhallaVault,
   createCancelInstruction,
+  getVaultOptionalAccounts,
 } from "@valhalla/lib";
 
 import { Connection } from "@solana/web3.js";
@@ -23,7 +24,8 @@ export const cancel = async (
     !wallet.publicKey ||
     !vault.vaultAta ||
     !vault.tokenProgramId ||
-    !vault.creatorAta
+    !vault.creatorAta ||
+    !vault.recipientAtaAddress
   ) {
     toast.error("Missing wallet or vault data");
     return;
@@ -62,7 +64,9 @@ export const cancel = async (
     recipient: vault.recipient,
     vault: vault.key,
     vaultAta: vault.vaultAta.address,
+    ...getVaultOptionalAccounts(vault),
     creatorAta: vault.creatorAta.address,
+    recipientAta: vault.recipientAtaAddress,
     mint: vault.mint,
     tokenProgram: vault.tokenProgramId,
     associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -2,6 +2,7 @@ import {
   CloseInstructionAccounts,
   ValhallaVault,
   createCloseInstruction,
+  getVaultOptionalAccounts,
 } from "@valhalla/lib";
 
 import { Connection } from "@solana/web3.js";
@@ -57,6 +58,7 @@ export const close = async (
     creator: vault.creator,
     vault: vault.key,
     vaultAta: vault.vaultAta.address,
+    ...getVaultOptionalAccounts(vault),
     mint: vault.mint,
     tokenProgram: vault.tokenProgramId,
   };
@@ -7,11 +7,14 @@ import {
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
 import {
+  Authority,
   Config,
   CreateInstructionAccounts,
   CreateInstructionArgs,
   PROGRAM_ID,
+  VestingMode,
   createCreateInstruction,
+  getFeePDAs,
   getMintWithCorrectTokenProgram,
   getNameArg,
   getPDAs,
@@ -184,22 +187,30 @@ const getInstructions = async (
     totalVestingDuration: Math.ceil(Number(totalVestingDuration / 1000)),
     startDate: Math.ceil(new Date(values.startDate).getTime() / 1000),
     payoutInterval: Math.ceil(values.payoutInterval / 1000),
+    cliffDate: 0,
+    cliffAmount: 0,
+    vestingMode: VestingMode.Interval,
     cancelAuthority: +values.cancelAuthority,
+    transferAuthority: Authority.Neither,
     autopay: values.autopay,
+    allowPublicDeposits: false,
   };
 
   const mint = new PublicKey(values.selectedToken.id);
   const tokenProgramId = (await connection.getAccountInfo(mint))?.owner;
   const recipient = new PublicKey(values.recipient);
-  const { config, vault, vaultAta } = getPDAs(
+  const { config, vault, vaultAta, autopayEscrow } = getPDAs(
     PROGRAM_ID,
     identifier,
     wallet.publicKey,
     mint
   );
+  const { feeVault, feeStats, mintFeeOverride } = getFeePDAs(PROGRAM_ID, mint);
 
-  const { devTreasury, daoTreasury, governanceTokenMintKey } =
-    await Config.fromAccountAddress(connection, config);
+  const { governanceTokenMintKey } = await Config.fromAccountAddress(
+    connection,
+    config
+  );
 
   const creatorAta = getAssociatedTokenAddressSync(
     mint,
@@ -215,22 +226,16 @@ const getInstructions = async (
     TOKEN_PROGRAM_ID
   );
 
-  const daoTreasuryAta = getAssociatedTokenAddressSync(
-    mint,
-    daoTreasury,
-    false,
-    tokenProgramId
-  );
-
   const createInstructionAccounts: CreateInstructionAccounts = {
     creator: wallet.publicKey,
     recipient,
-    devTreasury,
-    daoTreasury,
     config,
     vault,
     vaultAta,
-    daoTreasuryAta,
+    feeVault,
+    feeStats,
+    mintFeeOverride,
+    autopayEscrow: values.autopay ? autopayEscrow : undefined,
     creatorAta,
     mint,
     creatorGovernanceAta,
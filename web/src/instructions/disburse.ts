@@ -10,6 +10,7 @@ import {
   ValhallaVault,
   createDisburseInstruction,
   getPDAs,
+  getVaultOptionalAccounts,
 } from "@valhalla/lib";
 
 import { Connection } from "@solana/web3.js";
@@ -62,6 +63,7 @@ export const disburse = async (
     recipient: vault.recipient,
     vault: vault.key,
     vaultAta: vault.vaultAta.address,
+    ...getVaultOptionalAccounts(vault),
     mint: vault.mint,
     devTreasury: configAccount.devTreasury,
     config,